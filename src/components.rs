@@ -0,0 +1,284 @@
+/// Connected components: regions of a grid found by flood fill, and a disjoint-set forest
+/// for connecting elements one at a time, e.g. while removing obstacles in reverse order of their arrival.
+use std::collections::HashSet;
+
+use crate::grid::Grid;
+
+/// A connected set of grid positions.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Region {
+    cells: HashSet<(usize, usize)>,
+}
+
+impl Region {
+    pub fn cells(&self) -> &HashSet<(usize, usize)> {
+        &self.cells
+    }
+
+    pub fn contains(&self, position: (usize, usize)) -> bool {
+        self.cells.contains(&position)
+    }
+
+    pub fn area(&self) -> usize {
+        self.cells.len()
+    }
+
+    /// Number of cell edges that do not border another cell of the region.
+    pub fn perimeter(&self) -> usize {
+        self.cells
+            .iter()
+            .map(|(x, y)| {
+                let (x, y) = to_signed((*x, *y));
+                OFFSETS_4
+                    .iter()
+                    .filter(|(dx, dy)| !self.contains_signed(x + dx, y + dy))
+                    .count()
+            })
+            .sum()
+    }
+
+    /// Number of corners of the outline, including the outlines of holes.
+    /// This equals the number of straight sides of the region.
+    pub fn corners(&self) -> usize {
+        self.cells
+            .iter()
+            .map(|(x, y)| {
+                let (x, y) = to_signed((*x, *y));
+                DIAGONALS
+                    .iter()
+                    .filter(|(dx, dy)| {
+                        let horizontal = self.contains_signed(x + dx, y);
+                        let vertical = self.contains_signed(x, y + dy);
+                        let diagonal = self.contains_signed(x + dx, y + dy);
+                        // convex corners have no neighbours on either side, concave ones miss the diagonal.
+                        (!horizontal && !vertical) || (horizontal && vertical && !diagonal)
+                    })
+                    .count()
+            })
+            .sum()
+    }
+
+    pub fn sides(&self) -> usize {
+        self.corners()
+    }
+
+    /// Top left and bottom right corner of the smallest rectangle containing the region, both inclusive.
+    pub fn bounding_box(&self) -> ((usize, usize), (usize, usize)) {
+        let xs = self.cells.iter().map(|(x, _)| *x);
+        let ys = self.cells.iter().map(|(_, y)| *y);
+
+        (
+            (xs.clone().min().unwrap_or(0), ys.clone().min().unwrap_or(0)),
+            (xs.max().unwrap_or(0), ys.max().unwrap_or(0)),
+        )
+    }
+
+    fn contains_signed(&self, x: i64, y: i64) -> bool {
+        match (usize::try_from(x), usize::try_from(y)) {
+            (Ok(x), Ok(y)) => self.contains((x, y)),
+            _ => false,
+        }
+    }
+}
+
+const OFFSETS_4: [(i64, i64); 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+const DIAGONALS: [(i64, i64); 4] = [(1, -1), (1, 1), (-1, 1), (-1, -1)];
+
+#[allow(clippy::cast_possible_wrap)]
+fn to_signed((x, y): (usize, usize)) -> (i64, i64) {
+    (x as i64, y as i64)
+}
+
+/// Collects the region around `start`. A neighbour joins the region if `connected(cell, neighbour)` holds,
+/// e.g. `|a, b| a == b` for areas of the same plant.
+pub fn flood_fill<T>(
+    grid: &Grid<T>,
+    start: (usize, usize),
+    connected: impl Fn(&T, &T) -> bool,
+) -> Region {
+    let mut cells = HashSet::from([start]);
+    let mut stack = vec![start];
+
+    while let Some(position) = stack.pop() {
+        for next in grid.neighbours_4(position) {
+            if !cells.contains(&next) && connected(&grid[position], &grid[next]) {
+                cells.insert(next);
+                stack.push(next);
+            }
+        }
+    }
+
+    Region { cells }
+}
+
+/// Splits the whole grid into regions, in row order of their first cell.
+/// `connected` should be symmetric, otherwise regions depend on where the fill starts.
+pub fn regions<T>(grid: &Grid<T>, connected: impl Fn(&T, &T) -> bool) -> Vec<Region> {
+    let mut seen: HashSet<(usize, usize)> = HashSet::new();
+    let mut regions = vec![];
+
+    for (position, _) in grid.iter() {
+        if seen.contains(&position) {
+            continue;
+        }
+
+        let region = flood_fill(grid, position, &connected);
+        seen.extend(region.cells.iter().copied());
+        regions.push(region);
+    }
+
+    regions
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Disjoint sets of the elements `0..n`, with union by size and path compression.
+///
+/// ```
+/// # use advent_of_code::components::UnionFind;
+/// let mut sets = UnionFind::new(4);
+/// sets.union(0, 1);
+/// sets.union(2, 3);
+/// assert!(!sets.same(1, 2));
+/// assert_eq!(sets.count(), 2);
+/// ```
+#[derive(Clone, Debug)]
+pub struct UnionFind {
+    parents: Vec<usize>,
+    sizes: Vec<usize>,
+    count: usize,
+}
+
+impl UnionFind {
+    pub fn new(n: usize) -> Self {
+        UnionFind {
+            parents: (0..n).collect(),
+            sizes: vec![1; n],
+            count: n,
+        }
+    }
+
+    /// Representative element of the set containing `x`.
+    pub fn find(&mut self, x: usize) -> usize {
+        let mut root = x;
+        while self.parents[root] != root {
+            root = self.parents[root];
+        }
+
+        let mut current = x;
+        while self.parents[current] != root {
+            let next = self.parents[current];
+            self.parents[current] = root;
+            current = next;
+        }
+
+        root
+    }
+
+    /// Merges the sets of `a` and `b`. Returns `false` if they already were in the same set.
+    pub fn union(&mut self, a: usize, b: usize) -> bool {
+        let (a, b) = (self.find(a), self.find(b));
+        if a == b {
+            return false;
+        }
+
+        let (large, small) = if self.sizes[a] >= self.sizes[b] {
+            (a, b)
+        } else {
+            (b, a)
+        };
+        self.parents[small] = large;
+        self.sizes[large] += self.sizes[small];
+        self.count -= 1;
+
+        true
+    }
+
+    pub fn same(&mut self, a: usize, b: usize) -> bool {
+        self.find(a) == self.find(b)
+    }
+
+    /// Number of elements in the set containing `x`.
+    pub fn set_size(&mut self, x: usize) -> usize {
+        let root = self.find(x);
+        self.sizes[root]
+    }
+
+    /// Number of disjoint sets.
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    pub fn len(&self) -> usize {
+        self.parents.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.parents.is_empty()
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{flood_fill, regions, UnionFind};
+    use crate::grid::Grid;
+
+    #[test]
+    fn measures_garden_regions() {
+        let grid = Grid::parse("AAAA\nBBCD\nBBCC\nEEEC\n", |c| c);
+        let regions = regions(&grid, |a, b| a == b);
+
+        let metrics: Vec<_> = regions
+            .iter()
+            .map(|x| {
+                (
+                    grid[*x.cells().iter().next().unwrap()],
+                    x.area(),
+                    x.perimeter(),
+                    x.sides(),
+                )
+            })
+            .collect();
+
+        assert_eq!(
+            metrics,
+            vec![
+                ('A', 4, 10, 4),
+                ('B', 4, 8, 4),
+                ('C', 4, 10, 8),
+                ('D', 1, 4, 4),
+                ('E', 3, 8, 4)
+            ]
+        );
+        assert_eq!(regions[2].bounding_box(), ((2, 1), (3, 3)));
+    }
+
+    #[test]
+    fn counts_sides_of_holes() {
+        let grid = Grid::parse("OOOOO\nOXOXO\nOOOOO\nOXOXO\nOOOOO\n", |c| c);
+        let region = flood_fill(&grid, (0, 0), |a, b| a == b);
+
+        assert_eq!(region.area(), 21);
+        assert_eq!(region.perimeter(), 36);
+        assert_eq!(region.sides(), 20);
+        assert!(!region.contains((1, 1)));
+    }
+
+    #[test]
+    fn merges_sets() {
+        let mut sets = UnionFind::new(6);
+        assert!(sets.union(0, 1));
+        assert!(sets.union(1, 2));
+        assert!(!sets.union(0, 2));
+        assert!(sets.union(4, 5));
+
+        assert!(sets.same(0, 2));
+        assert!(!sets.same(2, 3));
+        assert_eq!(sets.set_size(2), 3);
+        assert_eq!(sets.set_size(3), 1);
+        assert_eq!(sets.count(), 3);
+        assert_eq!(sets.len(), 6);
+    }
+}
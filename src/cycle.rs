@@ -0,0 +1,194 @@
+/// Cycle detection for simulations that step from one state to the next.
+/// Floyd's and Brent's algorithms only keep a few states around, while the hash-based
+/// variants remember every state, which also works for simulations that may end.
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// A cycle in a sequence of states `x0, step(x0), step(step(x0)), ...`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Cycle {
+    /// Index of the first state that is part of the cycle.
+    pub start: usize,
+    /// Number of steps until a state repeats.
+    pub length: usize,
+}
+
+impl Cycle {
+    /// Index of the state before the cycle or in its first round that equals the state at index `n`.
+    pub fn position(&self, n: usize) -> usize {
+        if n < self.start {
+            n
+        } else {
+            self.start + (n - self.start) % self.length
+        }
+    }
+}
+
+/// Floyd's tortoise and hare. `step` is called about three times per state of the start and the cycle.
+pub fn floyd<T: Clone + PartialEq>(x0: T, mut step: impl FnMut(&T) -> T) -> Cycle {
+    let mut tortoise = step(&x0);
+    let mut hare = step(&tortoise);
+
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        let next = step(&hare);
+        hare = step(&next);
+    }
+
+    let mut start = 0;
+    tortoise = x0;
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        start += 1;
+    }
+
+    let mut length = 1;
+    hare = step(&tortoise);
+    while tortoise != hare {
+        hare = step(&hare);
+        length += 1;
+    }
+
+    Cycle { start, length }
+}
+
+/// Brent's algorithm, which usually needs fewer calls of `step` than [`floyd`].
+pub fn brent<T: Clone + PartialEq>(x0: T, mut step: impl FnMut(&T) -> T) -> Cycle {
+    let mut power = 1;
+    let mut length = 1;
+    let mut tortoise = x0.clone();
+    let mut hare = step(&x0);
+
+    while tortoise != hare {
+        if power == length {
+            tortoise = hare.clone();
+            power *= 2;
+            length = 0;
+        }
+        hare = step(&hare);
+        length += 1;
+    }
+
+    tortoise = x0.clone();
+    hare = x0;
+    for _ in 0..length {
+        hare = step(&hare);
+    }
+
+    let mut start = 0;
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        start += 1;
+    }
+
+    Cycle { start, length }
+}
+
+/// Remembers every state until one repeats. Returns `None` if `step` ends the simulation first,
+/// e.g. when a guard leaves the map instead of walking in a loop.
+pub fn find<T: Clone + Eq + Hash>(x0: T, mut step: impl FnMut(&T) -> Option<T>) -> Option<Cycle> {
+    let mut seen: HashMap<T, usize> = HashMap::new();
+    let mut state = x0;
+    let mut index = 0;
+
+    loop {
+        if let Some(start) = seen.insert(state.clone(), index) {
+            return Some(Cycle {
+                start,
+                length: index - start,
+            });
+        }
+        state = step(&state)?;
+        index += 1;
+    }
+}
+
+/// The state after `n` steps, skipping the repetitions of a cycle once it is found.
+///
+/// ```
+/// # use advent_of_code::cycle::nth;
+/// assert_eq!(nth(1_u64, |x| x * 3 % 7, 1_000_000_000_000), 4);
+/// ```
+pub fn nth<T: Clone + Eq + Hash>(x0: T, mut step: impl FnMut(&T) -> T, n: usize) -> T {
+    let mut seen: HashMap<T, usize> = HashMap::new();
+    let mut states = vec![];
+    let mut state = x0;
+
+    for index in 0..n {
+        if let Some(start) = seen.get(&state) {
+            let cycle = Cycle {
+                start: *start,
+                length: index - start,
+            };
+            return states.swap_remove(cycle.position(n));
+        }
+
+        seen.insert(state.clone(), index);
+        let next = step(&state);
+        states.push(state);
+        state = next;
+    }
+
+    state
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{brent, find, floyd, nth, Cycle};
+
+    // 0 -> 1 -> 2 -> 3 -> 4 -> 5 -> 2 -> ...
+    fn step(x: &u32) -> u32 {
+        if *x == 5 {
+            2
+        } else {
+            x + 1
+        }
+    }
+
+    #[test]
+    fn detects_cycles() {
+        let expected = Cycle {
+            start: 2,
+            length: 4,
+        };
+
+        assert_eq!(floyd(0, step), expected);
+        assert_eq!(brent(0, step), expected);
+        assert_eq!(find(0, |x| Some(step(x))), Some(expected));
+        assert_eq!(find(0, |x| (*x < 10).then_some(x + 1)), None);
+
+        assert_eq!(
+            floyd(2, step),
+            Cycle {
+                start: 0,
+                length: 4
+            }
+        );
+        assert_eq!(
+            brent(7, |_| 7),
+            Cycle {
+                start: 0,
+                length: 1
+            }
+        );
+    }
+
+    #[test]
+    fn jumps_forward() {
+        let cycle = Cycle {
+            start: 2,
+            length: 4,
+        };
+        assert_eq!(cycle.position(1), 1);
+        assert_eq!(cycle.position(6), 2);
+        assert_eq!(cycle.position(1_000_000_000_003), 3);
+
+        assert_eq!(nth(0, step, 1), 1);
+        assert_eq!(nth(0, step, 1_000_000_000_003), 3);
+        assert_eq!(nth(0, |x| x + 1, 50), 50);
+    }
+}
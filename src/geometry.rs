@@ -0,0 +1,265 @@
+/// Points and directions on a 2D plane, using the same orientation as [`Grid`](crate::grid::Grid):
+/// `x` grows to the right and `y` grows downwards, so `Direction::Up` decreases `y`.
+use std::error::Error;
+use std::fmt::{self, Display};
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+/// A position or offset with signed coordinates.
+///
+/// ```
+/// # use advent_of_code::geometry::{Direction, Point};
+/// let p = Point::new(2, 3) + Direction::Left.offset() * 3;
+/// assert_eq!(p, Point::new(-1, 3));
+/// assert_eq!(p.rem_euclid(Point::new(5, 5)), Point::new(4, 3));
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point {
+    pub x: i64,
+    pub y: i64,
+}
+
+impl Point {
+    pub const ORIGIN: Point = Point::new(0, 0);
+
+    pub const fn new(x: i64, y: i64) -> Self {
+        Point { x, y }
+    }
+
+    pub const fn manhattan_distance(self, other: Point) -> u64 {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+
+    /// Wraps the point into the rectangle from the origin to `size` (exclusive).
+    pub const fn rem_euclid(self, size: Point) -> Self {
+        Point::new(self.x.rem_euclid(size.x), self.y.rem_euclid(size.y))
+    }
+
+    /// Moves the point one step into `direction`.
+    pub fn step(self, direction: Direction) -> Self {
+        self + direction.offset()
+    }
+
+    /// The position of the point on a grid, if neither coordinate is negative.
+    pub fn to_position(self) -> Option<(usize, usize)> {
+        Some((usize::try_from(self.x).ok()?, usize::try_from(self.y).ok()?))
+    }
+}
+
+impl From<(i64, i64)> for Point {
+    fn from((x, y): (i64, i64)) -> Self {
+        Point::new(x, y)
+    }
+}
+
+impl From<(usize, usize)> for Point {
+    #[allow(clippy::cast_possible_wrap)]
+    fn from((x, y): (usize, usize)) -> Self {
+        Point::new(x as i64, y as i64)
+    }
+}
+
+impl Add for Point {
+    type Output = Point;
+
+    fn add(self, rhs: Point) -> Self::Output {
+        Point::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl AddAssign for Point {
+    fn add_assign(&mut self, rhs: Point) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub for Point {
+    type Output = Point;
+
+    fn sub(self, rhs: Point) -> Self::Output {
+        Point::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl SubAssign for Point {
+    fn sub_assign(&mut self, rhs: Point) {
+        *self = *self - rhs;
+    }
+}
+
+impl Mul<i64> for Point {
+    type Output = Point;
+
+    fn mul(self, rhs: i64) -> Self::Output {
+        Point::new(self.x * rhs, self.y * rhs)
+    }
+}
+
+impl Neg for Point {
+    type Output = Point;
+
+    fn neg(self) -> Self::Output {
+        Point::new(-self.x, -self.y)
+    }
+}
+
+impl Display for Point {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// One of the four orthogonal directions.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction {
+    /// All directions, clockwise starting from up.
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    pub fn iter() -> impl Iterator<Item = Direction> {
+        Self::ALL.into_iter()
+    }
+
+    pub const fn offset(self) -> Point {
+        match self {
+            Direction::Up => Point::new(0, -1),
+            Direction::Right => Point::new(1, 0),
+            Direction::Down => Point::new(0, 1),
+            Direction::Left => Point::new(-1, 0),
+        }
+    }
+
+    pub const fn turn_right(self) -> Self {
+        match self {
+            Direction::Up => Direction::Right,
+            Direction::Right => Direction::Down,
+            Direction::Down => Direction::Left,
+            Direction::Left => Direction::Up,
+        }
+    }
+
+    pub const fn turn_left(self) -> Self {
+        match self {
+            Direction::Up => Direction::Left,
+            Direction::Right => Direction::Up,
+            Direction::Down => Direction::Right,
+            Direction::Left => Direction::Down,
+        }
+    }
+
+    pub const fn turn_around(self) -> Self {
+        match self {
+            Direction::Up => Direction::Down,
+            Direction::Right => Direction::Left,
+            Direction::Down => Direction::Up,
+            Direction::Left => Direction::Right,
+        }
+    }
+
+    pub const fn is_horizontal(self) -> bool {
+        matches!(self, Direction::Left | Direction::Right)
+    }
+
+    /// The arrow character that puzzles use for the direction.
+    pub const fn arrow(self) -> char {
+        match self {
+            Direction::Up => '^',
+            Direction::Right => '>',
+            Direction::Down => 'v',
+            Direction::Left => '<',
+        }
+    }
+}
+
+/// Parses arrows (`^>v<`), `UDLR` and compass letters (`NESW`), case-insensitively.
+impl TryFrom<char> for Direction {
+    type Error = DirectionFromCharError;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value.to_ascii_uppercase() {
+            '^' | 'U' | 'N' => Ok(Direction::Up),
+            '>' | 'R' | 'E' => Ok(Direction::Right),
+            'V' | 'D' | 'S' => Ok(Direction::Down),
+            '<' | 'L' | 'W' => Ok(Direction::Left),
+            _ => Err(DirectionFromCharError(value)),
+        }
+    }
+}
+
+impl Display for Direction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.arrow())
+    }
+}
+
+/// An error which can be returned when parsing a [`Direction`].
+#[derive(Debug, PartialEq, Eq)]
+pub struct DirectionFromCharError(pub char);
+
+impl Error for DirectionFromCharError {}
+
+impl Display for DirectionFromCharError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "expecting one of ^>v<, UDLR or NESW, got {:?}", self.0)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Direction, DirectionFromCharError, Point};
+
+    #[test]
+    fn calculates_with_points() {
+        let a = Point::new(1, 2);
+        let b = Point::new(-3, 4);
+        assert_eq!(a + b, Point::new(-2, 6));
+        assert_eq!(a - b, Point::new(4, -2));
+        assert_eq!(b * 2, Point::new(-6, 8));
+        assert_eq!(-a, Point::new(-1, -2));
+        assert_eq!(a.manhattan_distance(b), 6);
+        assert_eq!(
+            Point::new(-1, 104).rem_euclid(Point::new(101, 103)),
+            Point::new(100, 1)
+        );
+        assert_eq!(Point::new(-1, 0).to_position(), None);
+        assert_eq!(Point::from((3_usize, 4_usize)).to_position(), Some((3, 4)));
+    }
+
+    #[test]
+    fn turns_directions() {
+        for direction in Direction::iter() {
+            assert_eq!(direction.turn_right().turn_left(), direction);
+            assert_eq!(direction.turn_right().turn_right(), direction.turn_around());
+            assert_eq!(
+                direction.offset() + direction.turn_around().offset(),
+                Point::ORIGIN
+            );
+        }
+        assert_eq!(Direction::Up.turn_right(), Direction::Right);
+        assert_eq!(Point::ORIGIN.step(Direction::Up), Point::new(0, -1));
+    }
+
+    #[test]
+    fn parses_directions() {
+        let parsed: Vec<_> = "^>v<".chars().map(Direction::try_from).collect();
+        assert_eq!(parsed, Direction::iter().map(Ok).collect::<Vec<_>>());
+        assert_eq!(Direction::try_from('l'), Ok(Direction::Left));
+        assert_eq!(Direction::try_from('N'), Ok(Direction::Up));
+        assert_eq!(Direction::try_from('x'), Err(DirectionFromCharError('x')));
+        assert_eq!(Direction::Down.to_string(), "v");
+    }
+}
@@ -0,0 +1,435 @@
+/// A rectangular grid of cells, as found in many puzzle inputs.
+/// Positions are `(x, y)` pairs, with `(0, 0)` in the top left corner and `y` growing downwards.
+use std::fmt::{self, Display};
+use std::ops::{Index, IndexMut};
+
+use crate::geometry::Point;
+
+/// Offsets of the orthogonal neighbours of a cell: up, right, down, left.
+const OFFSETS_4: [(i64, i64); 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+
+/// Offsets of all neighbours of a cell, clockwise starting from up.
+const OFFSETS_8: [(i64, i64); 8] = [
+    (0, -1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+    (0, 1),
+    (-1, 1),
+    (-1, 0),
+    (-1, -1),
+];
+
+/// Cells are stored row by row in a single `Vec`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Creates a grid from cells in row order.
+    ///
+    /// # Panics
+    /// If `width` is zero or the number of cells is not a multiple of it.
+    pub fn from_vec(width: usize, cells: Vec<T>) -> Self {
+        assert!(
+            width > 0 && cells.len().is_multiple_of(width),
+            "{} cells do not fill rows of width {width}.",
+            cells.len()
+        );
+
+        Grid {
+            width,
+            height: cells.len() / width,
+            cells,
+        }
+    }
+
+    /// Parses lines of characters, mapping each character with `cell`.
+    /// Trailing empty lines are ignored.
+    ///
+    /// # Panics
+    /// If the input has no cells or the lines differ in length.
+    pub fn parse(input: &str, mut cell: impl FnMut(char) -> T) -> Self {
+        let lines: Vec<&str> = input.trim_end_matches(['\n', '\r']).lines().collect();
+        let width = lines.first().map_or(0, |x| x.chars().count());
+        assert!(width > 0, "the input has no cells.");
+
+        let mut cells = Vec::with_capacity(width * lines.len());
+
+        for (y, line) in lines.iter().enumerate() {
+            let len = line.chars().count();
+            assert!(
+                len == width,
+                "line {} has {len} cells, expected {width}.",
+                y + 1
+            );
+            cells.extend(line.chars().map(&mut cell));
+        }
+
+        Grid {
+            width,
+            height: lines.len(),
+            cells,
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn in_bounds(&self, (x, y): (usize, usize)) -> bool {
+        x < self.width && y < self.height
+    }
+
+    /// Converts signed coordinates to a position, if they are inside the grid.
+    pub fn checked_position(&self, x: i64, y: i64) -> Option<(usize, usize)> {
+        let position = (usize::try_from(x).ok()?, usize::try_from(y).ok()?);
+        self.in_bounds(position).then_some(position)
+    }
+
+    /// Converts signed coordinates to a position, wrapping around the edges of the grid.
+    ///
+    /// # Panics
+    /// If the grid has no cells.
+    #[allow(
+        clippy::cast_possible_truncation,
+        clippy::cast_possible_wrap,
+        clippy::cast_sign_loss
+    )]
+    pub fn wrapping_position(&self, x: i64, y: i64) -> (usize, usize) {
+        assert!(!self.cells.is_empty(), "cannot wrap around an empty grid.");
+
+        (
+            x.rem_euclid(self.width as i64) as usize,
+            y.rem_euclid(self.height as i64) as usize,
+        )
+    }
+
+    pub fn get(&self, position: (usize, usize)) -> Option<&T> {
+        self.in_bounds(position)
+            .then(|| &self.cells[self.offset(position)])
+    }
+
+    pub fn get_mut(&mut self, position: (usize, usize)) -> Option<&mut T> {
+        if !self.in_bounds(position) {
+            return None;
+        }
+
+        let offset = self.offset(position);
+        Some(&mut self.cells[offset])
+    }
+
+    /// Returns the cell at signed coordinates, or `None` if they are outside the grid.
+    pub fn get_checked(&self, x: i64, y: i64) -> Option<&T> {
+        self.get(self.checked_position(x, y)?)
+    }
+
+    /// Returns the cell at signed coordinates, wrapping around the edges of the grid.
+    pub fn get_wrapping(&self, x: i64, y: i64) -> &T {
+        &self[self.wrapping_position(x, y)]
+    }
+
+    /// Returns the cell at `point`, or `None` if it is outside the grid.
+    pub fn get_point(&self, point: Point) -> Option<&T> {
+        self.get_checked(point.x, point.y)
+    }
+
+    pub fn get_point_mut(&mut self, point: Point) -> Option<&mut T> {
+        let position = self.checked_position(point.x, point.y)?;
+        self.get_mut(position)
+    }
+
+    /// Returns the cell at `point`, wrapping around the edges of the grid.
+    pub fn get_point_wrapping(&self, point: Point) -> &T {
+        self.get_wrapping(point.x, point.y)
+    }
+
+    /// Positions of the up to 4 orthogonal neighbours of `position` inside the grid.
+    pub fn neighbours_4(
+        &self,
+        position: (usize, usize),
+    ) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.neighbours(position, &OFFSETS_4)
+    }
+
+    /// Positions of the up to 8 neighbours of `position` inside the grid, including diagonals.
+    pub fn neighbours_8(
+        &self,
+        position: (usize, usize),
+    ) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.neighbours(position, &OFFSETS_8)
+    }
+
+    #[allow(clippy::cast_possible_wrap)]
+    fn neighbours(
+        &self,
+        (x, y): (usize, usize),
+        offsets: &'static [(i64, i64)],
+    ) -> impl Iterator<Item = (usize, usize)> + '_ {
+        offsets
+            .iter()
+            .filter_map(move |(dx, dy)| self.checked_position(x as i64 + dx, y as i64 + dy))
+    }
+
+    /// Position of the first cell in row order that matches `predicate`.
+    pub fn find(&self, predicate: impl Fn(&T) -> bool) -> Option<(usize, usize)> {
+        self.cells
+            .iter()
+            .position(predicate)
+            .map(|i| self.position(i))
+    }
+
+    /// Positions of all cells that match `predicate`, in row order.
+    pub fn find_all<'a>(
+        &'a self,
+        predicate: impl Fn(&T) -> bool + 'a,
+    ) -> impl Iterator<Item = (usize, usize)> + 'a {
+        self.iter()
+            .filter(move |(_, cell)| predicate(cell))
+            .map(|(position, _)| position)
+    }
+
+    /// All cells with their positions, in row order.
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        self.cells
+            .iter()
+            .enumerate()
+            .map(|(i, cell)| (self.position(i), cell))
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    /// Cells of column `x`, from top to bottom. Panics if `x` is outside the grid, like [`Grid::row`].
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(
+            x < self.width,
+            "column {x} is outside of the {}x{} grid.",
+            self.width,
+            self.height
+        );
+
+        self.cells.iter().skip(x).step_by(self.width.max(1))
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    /// Creates a grid of the same size with every cell mapped by `f`.
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    fn offset(&self, (x, y): (usize, usize)) -> usize {
+        y * self.width + x
+    }
+
+    fn position(&self, offset: usize) -> (usize, usize) {
+        (offset % self.width, offset / self.width)
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    /// Creates a grid with every cell set to `value`.
+    pub fn new(width: usize, height: usize, value: T) -> Self {
+        Grid {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: (usize, usize)) -> &Self::Output {
+        self.get(position).unwrap_or_else(|| {
+            panic!(
+                "position {position:?} is outside of the {}x{} grid.",
+                self.width, self.height
+            )
+        })
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, position: (usize, usize)) -> &mut Self::Output {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(position).unwrap_or_else(|| {
+            panic!("position {position:?} is outside of the {width}x{height} grid.")
+        })
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point) -> &Self::Output {
+        self.get_point(point).unwrap_or_else(|| {
+            panic!(
+                "point {point} is outside of the {}x{} grid.",
+                self.width, self.height
+            )
+        })
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, point: Point) -> &mut Self::Output {
+        let (width, height) = (self.width, self.height);
+        self.get_point_mut(point)
+            .unwrap_or_else(|| panic!("point {point} is outside of the {width}x{height} grid."))
+    }
+}
+
+/// Prints one line per row, without separators between cells.
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::Grid;
+    use crate::geometry::{Direction, Point};
+
+    fn get_mock_grid() -> Grid<u32> {
+        Grid::parse("012\n345\n678\n", |c| c.to_digit(10).unwrap())
+    }
+
+    #[test]
+    fn parses_and_displays() {
+        let grid = get_mock_grid();
+        assert_eq!(grid.width(), 3);
+        assert_eq!(grid.height(), 3);
+        assert_eq!(grid[(2, 1)], 5);
+        assert_eq!(grid.to_string(), "012\n345\n678");
+        assert_eq!(Grid::from_vec(3, (0..9).collect()), get_mock_grid());
+    }
+
+    #[test]
+    fn parses_characters() {
+        let grid = Grid::parse("#.\n.S", |c| c);
+        assert_eq!(grid.find(|c| *c == 'S'), Some((1, 1)));
+        assert_eq!(grid[(0, 0)], '#');
+    }
+
+    #[test]
+    #[should_panic(expected = "the input has no cells.")]
+    fn panics_for_empty_input() {
+        Grid::parse("\n", |c| c);
+    }
+
+    #[test]
+    #[should_panic(expected = "line 2 has 2 cells, expected 3.")]
+    fn panics_for_ragged_lines() {
+        Grid::parse("abc\nde", |c| c);
+    }
+
+    #[test]
+    fn indexes_checked_and_wrapping() {
+        let mut grid = get_mock_grid();
+        assert_eq!(grid.get((3, 0)), None);
+        assert_eq!(grid.get_checked(-1, 0), None);
+        assert_eq!(grid.get_checked(1, 2), Some(&7));
+        assert_eq!(grid.get_wrapping(-1, 4), &5);
+        grid[(0, 0)] = 9;
+        *grid.get_mut((1, 0)).unwrap() = 8;
+        assert_eq!(grid.row(0), &[9, 8, 2]);
+        assert_eq!(grid.get_mut((0, 3)), None);
+        assert_eq!(grid.get_mut((usize::MAX, usize::MAX)), None);
+    }
+
+    #[test]
+    fn indexes_with_points() {
+        let mut grid = get_mock_grid();
+        let start: Point = grid.find(|x| *x == 4).unwrap().into();
+        assert_eq!(grid[start.step(Direction::Up)], 1);
+        assert_eq!(grid.get_point(start + Direction::Left.offset() * 2), None);
+        assert_eq!(
+            grid.get_point_wrapping(start + Direction::Left.offset() * 2),
+            &5
+        );
+        grid[Point::ORIGIN] = 9;
+        assert_eq!(grid[(0, 0)], 9);
+    }
+
+    #[test]
+    fn iterates_neighbours() {
+        let grid = get_mock_grid();
+        assert_eq!(
+            grid.neighbours_4((0, 0)).collect::<Vec<_>>(),
+            vec![(1, 0), (0, 1)]
+        );
+        assert_eq!(grid.neighbours_4((1, 1)).count(), 4);
+        assert_eq!(grid.neighbours_8((1, 1)).count(), 8);
+        assert_eq!(
+            grid.neighbours_8((2, 2)).collect::<Vec<_>>(),
+            vec![(2, 1), (1, 2), (1, 1)]
+        );
+    }
+
+    #[test]
+    fn finds_positions() {
+        let grid = get_mock_grid();
+        assert_eq!(grid.find(|x| *x == 4), Some((1, 1)));
+        assert_eq!(grid.find(|x| *x == 10), None);
+        assert_eq!(
+            grid.find_all(|x| x % 3 == 0).collect::<Vec<_>>(),
+            vec![(0, 0), (0, 1), (0, 2)]
+        );
+    }
+
+    #[test]
+    fn iterates_rows_and_columns() {
+        let grid = get_mock_grid();
+        assert_eq!(grid.rows().nth(2), Some(&[6, 7, 8][..]));
+        assert_eq!(grid.column(1).copied().collect::<Vec<_>>(), vec![1, 4, 7]);
+        assert_eq!(
+            grid.columns().map(|x| x.sum::<u32>()).collect::<Vec<_>>(),
+            vec![9, 12, 15]
+        );
+        assert_eq!(grid.map(|x| x * 2)[(2, 2)], 16);
+    }
+
+    #[test]
+    #[should_panic(expected = "column 4 is outside of the 3x3 grid.")]
+    fn panics_for_columns_outside() {
+        let _ = get_mock_grid().column(4);
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot wrap around an empty grid.")]
+    fn panics_for_wrapping_around_empty_grids() {
+        let _ = Grid::<u32>::from_vec(3, Vec::new()).wrapping_position(1, 1);
+    }
+}
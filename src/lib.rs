@@ -1,3 +1,13 @@
+pub mod components;
+pub mod cycle;
+pub mod geometry;
+pub mod grid;
+pub mod math;
+pub mod memo;
+pub mod parse;
+pub mod pathfinding;
+pub mod simulation;
 pub mod template;
+pub mod visualize;
 
 // Use this file to add helper functions and additional modules.
@@ -1,12 +1,15 @@
-use advent_of_code::template::commands::{all, download, read, scaffold, solve, time};
+use advent_of_code::template::commands::{
+    all, download, export, inputs, leaderboard, read, scaffold, solve, stars, status, time,
+};
 use args::{parse, AppArguments};
 
 #[cfg(feature = "today")]
-use advent_of_code::template::Day;
-#[cfg(feature = "today")]
-use std::process;
+use advent_of_code::template::commands::today;
 
 mod args {
+    use advent_of_code::template::commands::export::Format;
+    use advent_of_code::template::commands::read::parse_part;
+    use advent_of_code::template::runner::{parse_seconds, BenchOverrides};
     use advent_of_code::template::Day;
     use std::process;
 
@@ -16,6 +19,20 @@ mod args {
         },
         Read {
             day: Day,
+            part: Option<u8>,
+            new: bool,
+        },
+        InputsEncrypt {
+            day: Option<Day>,
+        },
+        InputsDecrypt {
+            day: Option<Day>,
+            overwrite: bool,
+        },
+        Leaderboard {
+            id: u32,
+            day: Option<Day>,
+            refresh: bool,
         },
         Scaffold {
             day: Day,
@@ -27,17 +44,39 @@ mod args {
             release: bool,
             dhat: bool,
             submit: Option<u8>,
+            visualize: bool,
         },
         All {
             release: bool,
         },
+        Status {
+            run_tests: bool,
+        },
+        Stars {
+            store: bool,
+            leaderboard: Option<u32>,
+            user: Option<u64>,
+        },
+        Export {
+            format: Option<Format>,
+            output: Option<String>,
+            history: bool,
+            profile: Option<String>,
+        },
         Time {
             all: bool,
             day: Option<Day>,
             store: bool,
+            dry_run: bool,
+            report: Option<String>,
+            profile: Option<String>,
+            compare: bool,
+            bench: BenchOverrides,
         },
         #[cfg(feature = "today")]
-        Today,
+        Today {
+            wait: bool,
+        },
     }
 
     pub fn parse() -> Result<AppArguments, Box<dyn std::error::Error>> {
@@ -50,19 +89,68 @@ mod args {
             Some("time") => {
                 let all = args.contains("--all");
                 let store = args.contains("--store");
+                let dry_run = args.contains("--dry-run");
+                let report = args.opt_value_from_str("--report")?;
+                let profile = args.opt_value_from_str("--profile")?;
+                let compare = args.contains("--compare");
+                let bench = BenchOverrides {
+                    bench_time: args.opt_value_from_fn("--bench-time", parse_seconds)?,
+                    warmup: args.opt_value_from_fn("--warmup", parse_seconds)?,
+                    min_samples: args.opt_value_from_str("--min-samples")?,
+                    max_samples: args.opt_value_from_str("--max-samples")?,
+                };
 
                 AppArguments::Time {
                     all,
                     day: args.opt_free_from_str()?,
                     store,
+                    dry_run,
+                    report,
+                    profile,
+                    compare,
+                    bench,
                 }
             }
+            Some("export") => AppArguments::Export {
+                format: args.opt_value_from_str("--format")?,
+                output: args.opt_value_from_str("--output")?,
+                history: args.contains("--history"),
+                profile: args.opt_value_from_str("--profile")?,
+            },
+            Some("status") => AppArguments::Status {
+                run_tests: !args.contains("--no-tests"),
+            },
+            Some("stars") => AppArguments::Stars {
+                store: args.contains("--store"),
+                leaderboard: args.opt_value_from_str("--leaderboard")?,
+                user: args.opt_value_from_str("--user")?,
+            },
             Some("download") => AppArguments::Download {
                 day: args.free_from_str()?,
             },
             Some("read") => AppArguments::Read {
+                part: args.opt_value_from_fn("--part", parse_part)?,
+                new: args.contains("--new"),
                 day: args.free_from_str()?,
             },
+            Some("inputs") => match args.subcommand()?.as_deref() {
+                Some("encrypt") => AppArguments::InputsEncrypt {
+                    day: args.opt_free_from_str()?,
+                },
+                Some("decrypt") => AppArguments::InputsDecrypt {
+                    overwrite: args.contains("--overwrite"),
+                    day: args.opt_free_from_str()?,
+                },
+                _ => {
+                    eprintln!("Usage: inputs <encrypt|decrypt> [day]");
+                    process::exit(1);
+                }
+            },
+            Some("leaderboard") => AppArguments::Leaderboard {
+                day: args.opt_value_from_str("--day")?,
+                refresh: args.contains("--refresh"),
+                id: args.free_from_str()?,
+            },
             Some("scaffold") => AppArguments::Scaffold {
                 day: args.free_from_str()?,
                 download: args.contains("--download"),
@@ -73,9 +161,12 @@ mod args {
                 release: args.contains("--release"),
                 submit: args.opt_value_from_str("--submit")?,
                 dhat: args.contains("--dhat"),
+                visualize: args.contains("--visualize"),
             },
             #[cfg(feature = "today")]
-            Some("today") => AppArguments::Today,
+            Some("today") => AppArguments::Today {
+                wait: args.contains("--wait"),
+            },
             Some(x) => {
                 eprintln!("Unknown command: {x}");
                 process::exit(1);
@@ -103,9 +194,35 @@ fn main() {
         }
         Ok(args) => match args {
             AppArguments::All { release } => all::handle(release),
-            AppArguments::Time { day, all, store } => time::handle(day, all, store),
+            AppArguments::Export {
+                format,
+                output,
+                history,
+                profile,
+            } => export::handle(format, output, history, profile.as_deref()),
+            AppArguments::Time {
+                day,
+                all,
+                store,
+                dry_run,
+                report,
+                profile,
+                compare,
+                bench,
+            } => time::handle(day, all, store, dry_run, report, profile, compare, &bench),
+            AppArguments::Status { run_tests } => status::handle(run_tests),
+            AppArguments::Stars {
+                store,
+                leaderboard,
+                user,
+            } => stars::handle(store, leaderboard, user),
             AppArguments::Download { day } => download::handle(day),
-            AppArguments::Read { day } => read::handle(day),
+            AppArguments::Read { day, part, new } => read::handle(day, part, new),
+            AppArguments::InputsEncrypt { day } => inputs::encrypt(day),
+            AppArguments::InputsDecrypt { day, overwrite } => inputs::decrypt(day, overwrite),
+            AppArguments::Leaderboard { id, day, refresh } => {
+                leaderboard::handle(id, day, refresh);
+            }
             AppArguments::Scaffold { day, download, overwrite } => {
                 scaffold::handle(day, overwrite);
                 if download {
@@ -117,24 +234,10 @@ fn main() {
                 release,
                 dhat,
                 submit,
-            } => solve::handle(day, release, dhat, submit),
+                visualize,
+            } => solve::handle(day, release, dhat, submit, visualize),
             #[cfg(feature = "today")]
-            AppArguments::Today => {
-                match Day::today() {
-                    Some(day) => {
-                        scaffold::handle(day, false);
-                        download::handle(day);
-                        read::handle(day)
-                    }
-                    None => {
-                        eprintln!(
-                            "`today` command can only be run between the 1st and \
-                            the 25th of december. Please use `scaffold` with a specific day."
-                        );
-                        process::exit(1)
-                    }
-                };
-            }
+            AppArguments::Today { wait } => today::handle(wait),
         },
     };
 }
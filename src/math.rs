@@ -0,0 +1,219 @@
+/// Exact integer math that comes up in puzzles: 2×2 linear systems, gcd and lcm, modular inverses
+/// and the Chinese remainder theorem. Everything works on `i128`, and functions return `None` or an error on overflow.
+use std::error::Error;
+use std::fmt::{self, Display};
+
+/// Why a linear system has no unique integer solution.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LinearError {
+    /// The determinant is zero, so there are either no or infinitely many solutions.
+    Degenerate,
+    /// The unique solution is not integral.
+    NotIntegral,
+    Overflow,
+}
+
+impl Error for LinearError {}
+
+impl Display for LinearError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LinearError::Degenerate => f.write_str("the system has no unique solution"),
+            LinearError::NotIntegral => f.write_str("the solution is not integral"),
+            LinearError::Overflow => f.write_str("the calculation overflows i128"),
+        }
+    }
+}
+
+/// Solves `a * x + b * y = e` and `c * x + d * y = f` with Cramer's rule.
+///
+/// ```
+/// # use advent_of_code::math::solve_2x2;
+/// // button A: X+94, Y+34; button B: X+22, Y+67; prize: X=8400, Y=5400
+/// assert_eq!(solve_2x2([[94, 22], [34, 67]], [8400, 5400]), Ok((80, 40)));
+/// ```
+pub fn solve_2x2(
+    [[a, b], [c, d]]: [[i128; 2]; 2],
+    [e, f]: [i128; 2],
+) -> Result<(i128, i128), LinearError> {
+    let determinant = |p: i128, q: i128, r: i128, s: i128| {
+        p.checked_mul(s)
+            .zip(q.checked_mul(r))
+            .and_then(|(x, y)| x.checked_sub(y))
+            .ok_or(LinearError::Overflow)
+    };
+
+    let det = determinant(a, b, c, d)?;
+    if det == 0 {
+        return Err(LinearError::Degenerate);
+    }
+
+    let det_x = determinant(e, b, f, d)?;
+    let det_y = determinant(a, e, c, f)?;
+
+    // the division overflows for `i128::MIN / -1`.
+    let divide = |n: i128| match n.checked_rem(det).ok_or(LinearError::Overflow)? {
+        0 => n.checked_div(det).ok_or(LinearError::Overflow),
+        _ => Err(LinearError::NotIntegral),
+    };
+
+    Ok((divide(det_x)?, divide(det_y)?))
+}
+
+/// Greatest common divisor, always non-negative. `None` if it is `2^127`, e.g. for `gcd(i128::MIN, 0)`.
+pub fn gcd(a: i128, b: i128) -> Option<i128> {
+    let (mut a, mut b) = (a.unsigned_abs(), b.unsigned_abs());
+
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+
+    i128::try_from(a).ok()
+}
+
+/// Returns `(g, x, y)` with `a * x + b * y = g`, where `g` is the non-negative gcd of `a` and `b`.
+/// `None` if `g` or the coefficients overflow, which needs an argument of `i128::MIN`.
+pub fn extended_gcd(a: i128, b: i128) -> Option<(i128, i128, i128)> {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (1_i128, 0_i128);
+    let (mut old_y, mut y) = (0_i128, 1_i128);
+
+    while r != 0 {
+        let quotient = old_r.checked_div(r)?;
+        (old_r, r) = (r, old_r.checked_rem(r)?);
+        (old_x, x) = (x, old_x.checked_sub(quotient.checked_mul(x)?)?);
+        (old_y, y) = (y, old_y.checked_sub(quotient.checked_mul(y)?)?);
+    }
+
+    if old_r < 0 {
+        Some((
+            old_r.checked_neg()?,
+            old_x.checked_neg()?,
+            old_y.checked_neg()?,
+        ))
+    } else {
+        Some((old_r, old_x, old_y))
+    }
+}
+
+/// Least common multiple, or `None` on overflow. The lcm with 0 is 0.
+pub fn lcm(a: i128, b: i128) -> Option<i128> {
+    if a == 0 || b == 0 {
+        return Some(0);
+    }
+    (a / gcd(a, b)?).checked_mul(b)?.checked_abs()
+}
+
+/// Least common multiple of all values, e.g. the period of several cycles. `Some(1)` for no values.
+pub fn lcm_all(values: impl IntoIterator<Item = i128>) -> Option<i128> {
+    values.into_iter().try_fold(1, lcm)
+}
+
+/// The `x` in `0..m` with `a * x ≡ 1 (mod m)`, if `a` and `m` are coprime. `None` if `m` is not positive.
+pub fn mod_inverse(a: i128, m: i128) -> Option<i128> {
+    if m <= 0 {
+        return None;
+    }
+
+    let (g, x, _) = extended_gcd(a, m)?;
+    (g == 1).then(|| x.rem_euclid(m))
+}
+
+/// Combines congruences `x ≡ residue (mod modulus)` into a single `(residue, modulus)`.
+/// Moduli don't need to be coprime, but must be positive. Returns `None` if the congruences contradict each other or on overflow.
+///
+/// ```
+/// # use advent_of_code::math::crt;
+/// // robots repeat their x positions every 101 steps and their y positions every 103 steps.
+/// assert_eq!(crt([(12, 101), (50, 103)]), Some((8496, 10403)));
+/// ```
+pub fn crt(congruences: impl IntoIterator<Item = (i128, i128)>) -> Option<(i128, i128)> {
+    congruences
+        .into_iter()
+        .try_fold((0, 1), |(r1, m1), (r2, m2)| {
+            if m2 <= 0 {
+                return None;
+            }
+
+            let (g, p, _) = extended_gcd(m1, m2)?;
+            let difference = r2.checked_sub(r1)?;
+
+            if difference % g != 0 {
+                return None;
+            }
+
+            let modulus = lcm(m1, m2)?;
+            // r1 + m1 * k ≡ r2 (mod m2), with k = difference / g * p (mod m2 / g).
+            let k = (difference / g).checked_mul(p)?.rem_euclid(m2 / g);
+            let residue = m1.checked_mul(k)?.checked_add(r1)?.rem_euclid(modulus);
+
+            Some((residue, modulus))
+        })
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{crt, extended_gcd, gcd, lcm, lcm_all, mod_inverse, solve_2x2, LinearError};
+
+    #[test]
+    fn solves_linear_systems() {
+        assert_eq!(solve_2x2([[94, 22], [34, 67]], [8400, 5400]), Ok((80, 40)));
+        assert_eq!(
+            solve_2x2([[26, 67], [66, 21]], [12748, 12176]),
+            Err(LinearError::NotIntegral)
+        );
+        assert_eq!(
+            solve_2x2([[1, 2], [2, 4]], [3, 6]),
+            Err(LinearError::Degenerate)
+        );
+        assert_eq!(
+            solve_2x2([[i128::MAX, 2], [2, i128::MAX]], [1, 1]),
+            Err(LinearError::Overflow)
+        );
+        assert_eq!(
+            solve_2x2(
+                [[94, 22], [34, 67]],
+                [10_000_000_008_400, 10_000_000_005_400]
+            ),
+            Err(LinearError::NotIntegral)
+        );
+        assert_eq!(
+            solve_2x2([[-1, 0], [0, 1]], [i128::MIN, 0]),
+            Err(LinearError::Overflow)
+        );
+    }
+
+    #[test]
+    fn calculates_divisors() {
+        assert_eq!(gcd(12, -18), Some(6));
+        assert_eq!(gcd(i128::MIN, -1), Some(1));
+        assert_eq!(gcd(i128::MIN, 0), None);
+        let (g, x, y) = extended_gcd(240, 46).unwrap();
+        assert_eq!((g, 240 * x + 46 * y), (2, 2));
+        assert_eq!(extended_gcd(i128::MIN, 0), None);
+        assert_eq!(extended_gcd(i128::MIN, -1), None);
+        assert_eq!(lcm(4, 6), Some(12));
+        assert_eq!(lcm_all([101, 103, 2]), Some(20806));
+        assert_eq!(lcm_all([]), Some(1));
+        assert_eq!(lcm(i128::MAX, i128::MAX - 1), None);
+        assert_eq!(lcm(i128::MIN, 1), None);
+    }
+
+    #[test]
+    fn calculates_inverses_and_remainders() {
+        assert_eq!(mod_inverse(3, 11), Some(4));
+        assert_eq!(mod_inverse(-3, 11), Some(7));
+        assert_eq!(mod_inverse(6, 9), None);
+        assert_eq!(mod_inverse(1, 0), None);
+        assert_eq!(mod_inverse(1, -5), None);
+
+        assert_eq!(crt([(2, 3), (3, 5), (2, 7)]), Some((23, 105)));
+        assert_eq!(crt([(1, 4), (3, 6)]), Some((9, 12)));
+        assert_eq!(crt([(1, 4), (2, 6)]), None);
+        assert_eq!(crt([(12, 101), (50, 103)]), Some((8496, 10403)));
+        assert_eq!(crt([]), Some((0, 1)));
+        assert_eq!(crt([(1, 0)]), None);
+    }
+}
@@ -0,0 +1,178 @@
+/// A cache for memoizing recursive functions.
+/// The cache is passed down the recursion and looked up with borrowed keys, e.g. `&str` for `String` keys,
+/// so that keys are only cloned when a new value is stored.
+use std::borrow::Borrow;
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// Memoizes values of type `V` by keys of type `K`.
+/// Hit and miss counts are logged with `tracing` at debug level when the cache is dropped.
+///
+/// ```
+/// # use advent_of_code::memo::Memo;
+/// fn arrangements(design: &str, towels: &[&str], memo: &mut Memo<String, u64>) -> u64 {
+///     if design.is_empty() {
+///         return 1;
+///     }
+///     memo.compute(design, |memo| {
+///         towels
+///             .iter()
+///             .filter_map(|x| design.strip_prefix(x))
+///             .map(|rest| arrangements(rest, towels, memo))
+///             .sum()
+///     })
+/// }
+///
+/// let mut memo = Memo::new("arrangements");
+/// assert_eq!(arrangements("brwrr", &["r", "wr", "b", "g", "bwu", "rb", "gb", "br"], &mut memo), 2);
+/// ```
+#[derive(Clone, Debug)]
+pub struct Memo<K, V> {
+    name: &'static str,
+    cache: HashMap<K, V>,
+    limit: Option<usize>,
+    stats: MemoStats,
+}
+
+/// How often a [`Memo`] was used.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct MemoStats {
+    pub hits: u64,
+    pub misses: u64,
+    /// Number of times the cache was cleared because it reached its limit.
+    pub clears: u64,
+}
+
+impl<K: Eq + Hash, V: Clone> Memo<K, V> {
+    /// Creates an unbounded cache. `name` identifies the cache in the logged statistics.
+    pub fn new(name: &'static str) -> Self {
+        Memo {
+            name,
+            cache: HashMap::new(),
+            limit: None,
+            stats: MemoStats::default(),
+        }
+    }
+
+    /// Limits the cache to `limit` entries. A full cache is cleared before the next value is stored.
+    #[must_use]
+    pub fn with_limit(mut self, limit: usize) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Returns the cached value for `key`, counting a hit or miss.
+    pub fn get<Q>(&mut self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: Eq + Hash + ?Sized,
+    {
+        let value = self.cache.get(key).cloned();
+
+        if value.is_some() {
+            self.stats.hits += 1;
+        } else {
+            self.stats.misses += 1;
+        }
+
+        value
+    }
+
+    /// Stores `value` for `key` and returns it.
+    pub fn insert(&mut self, key: K, value: V) -> V {
+        if self.limit.is_some_and(|x| self.cache.len() >= x) {
+            self.cache.clear();
+            self.stats.clears += 1;
+        }
+
+        self.cache.insert(key, value.clone());
+        value
+    }
+
+    /// Returns the cached value for `key`, or computes and stores it with `f`.
+    /// `f` receives the cache so that it can recurse.
+    pub fn compute<Q>(&mut self, key: &Q, f: impl FnOnce(&mut Self) -> V) -> V
+    where
+        K: Borrow<Q>,
+        Q: Eq + Hash + ToOwned<Owned = K> + ?Sized,
+    {
+        if let Some(value) = self.get(key) {
+            return value;
+        }
+
+        let value = f(self);
+        self.insert(key.to_owned(), value)
+    }
+
+    pub fn len(&self) -> usize {
+        self.cache.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cache.is_empty()
+    }
+
+    pub fn stats(&self) -> MemoStats {
+        self.stats
+    }
+}
+
+impl<K, V> Drop for Memo<K, V> {
+    fn drop(&mut self) {
+        let MemoStats {
+            hits,
+            misses,
+            clears,
+        } = self.stats;
+
+        tracing::debug!(
+            memo = self.name,
+            hits,
+            misses,
+            clears,
+            entries = self.cache.len(),
+            "memo statistics"
+        );
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Memo, MemoStats};
+
+    fn fibonacci(n: u64, memo: &mut Memo<u64, u64>) -> u64 {
+        if n < 2 {
+            return n;
+        }
+        memo.compute(&n, |memo| fibonacci(n - 1, memo) + fibonacci(n - 2, memo))
+    }
+
+    #[test]
+    fn memoizes_recursion() {
+        let mut memo = Memo::new("fibonacci");
+        assert_eq!(fibonacci(90, &mut memo), 2_880_067_194_370_816_120);
+        assert_eq!(memo.len(), 89);
+        assert_eq!(
+            memo.stats(),
+            MemoStats {
+                hits: 87,
+                misses: 89,
+                clears: 0
+            }
+        );
+    }
+
+    #[test]
+    fn clears_full_cache() {
+        let mut memo: Memo<String, usize> = Memo::new("lengths").with_limit(2);
+        for word in ["a", "bb", "a", "ccc", "a"] {
+            memo.compute(word, |_| word.len());
+        }
+        assert_eq!(memo.len(), 2);
+        assert_eq!(memo.stats().hits, 1);
+        assert_eq!(memo.stats().clears, 1);
+        assert_eq!(memo.get("a"), Some(1));
+    }
+}
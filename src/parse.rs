@@ -0,0 +1,311 @@
+/// Helpers for parsing puzzle inputs that report where parsing failed.
+/// Every piece of the input is a [`Span`] that knows its day, line and column,
+/// so that errors can point to the offending text instead of panicking on `unwrap()`.
+use std::any::type_name;
+use std::error::Error;
+use std::fmt::{self, Display};
+use std::str::FromStr;
+
+use crate::template::Day;
+
+/// Wraps the input of a day, starting at line 1, column 1.
+///
+/// ```
+/// # use advent_of_code::{day, parse};
+/// let input = parse::input(day!(13), "Button A: X+94, Y+34\n\nPrize: X=8400, Y=5400\n");
+/// let sections = input.sections();
+/// assert_eq!(sections[1].ints::<i64>()?, vec![8400, 5400]);
+/// # Ok::<(), parse::ParseError>(())
+/// ```
+pub fn input(day: Day, text: &str) -> Span<'_> {
+    Span {
+        day,
+        line: 1,
+        column: 1,
+        text,
+    }
+}
+
+/// A part of the input together with its location.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Span<'a> {
+    day: Day,
+    line: usize,
+    column: usize,
+    text: &'a str,
+}
+
+impl<'a> Span<'a> {
+    pub fn text(&self) -> &'a str {
+        self.text
+    }
+
+    /// Line of the start of the span, starting at 1.
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    /// Column of the start of the span in characters, starting at 1.
+    pub fn column(&self) -> usize {
+        self.column
+    }
+
+    /// Creates an error that points to this span.
+    pub fn error(&self, message: impl Into<String>) -> ParseError {
+        ParseError {
+            day: self.day,
+            line: self.line,
+            column: self.column,
+            text: self.text.lines().next().unwrap_or_default().to_string(),
+            message: message.into(),
+        }
+    }
+
+    /// Locates `text`, which has to be a slice of this span.
+    fn sub(&self, text: &'a str) -> Span<'a> {
+        let offset = text.as_ptr() as usize - self.text.as_ptr() as usize;
+        let before = &self.text[..offset];
+
+        let (line, column) = match before.rfind('\n') {
+            Some(i) => (
+                self.line + before.matches('\n').count(),
+                before[i + 1..].chars().count() + 1,
+            ),
+            None => (self.line, self.column + before.chars().count()),
+        };
+
+        Span {
+            day: self.day,
+            line,
+            column,
+            text,
+        }
+    }
+
+    pub fn trim(&self) -> Span<'a> {
+        self.sub(self.text.trim())
+    }
+
+    /// Lines of the span, without line endings.
+    pub fn lines(&self) -> impl Iterator<Item = Span<'a>> + '_ {
+        self.text.lines().enumerate().map(|(i, text)| Span {
+            day: self.day,
+            line: self.line + i,
+            column: if i == 0 { self.column } else { 1 },
+            text,
+        })
+    }
+
+    /// Splits the span into sections separated by blank lines. Blank lines around sections are dropped.
+    pub fn sections(&self) -> Vec<Span<'a>> {
+        let mut sections = vec![];
+        let mut section: Option<(usize, usize)> = None;
+        let mut offset = 0;
+
+        for line in self.text.split_inclusive('\n') {
+            let content = line.trim_end_matches(['\n', '\r']);
+
+            if content.trim().is_empty() {
+                if let Some((start, end)) = section.take() {
+                    sections.push(self.sub(&self.text[start..end]));
+                }
+            } else {
+                let start = section.map_or(offset, |(start, _)| start);
+                section = Some((start, offset + content.len()));
+            }
+
+            offset += line.len();
+        }
+
+        if let Some((start, end)) = section {
+            sections.push(self.sub(&self.text[start..end]));
+        }
+
+        sections
+    }
+
+    /// Parses the trimmed span with [`FromStr`].
+    pub fn parse<T: FromStr>(&self) -> Result<T, ParseError> {
+        let trimmed = self.trim();
+        trimmed
+            .text
+            .parse()
+            .map_err(|_| trimmed.error(format!("expected {}", type_name::<T>())))
+    }
+
+    /// All integers in the span. A `-` directly in front of digits is a sign,
+    /// unless it follows a letter or digit, as in `1-3` or `a-5`.
+    pub fn ints<T: FromStr>(&self) -> Result<Vec<T>, ParseError> {
+        let mut ints = vec![];
+        let mut chars = self.text.char_indices().peekable();
+        let mut previous: Option<char> = None;
+
+        while let Some((start, c)) = chars.next() {
+            let is_sign = c == '-'
+                && chars.peek().is_some_and(|(_, x)| x.is_ascii_digit())
+                && !previous.is_some_and(char::is_alphanumeric);
+
+            if c.is_ascii_digit() || is_sign {
+                let mut end = start + c.len_utf8();
+                while let Some((i, x)) = chars.next_if(|(_, x)| x.is_ascii_digit()) {
+                    end = i + x.len_utf8();
+                }
+                ints.push(self.sub(&self.text[start..end]).parse()?);
+                previous = self.text[..end].chars().next_back();
+            } else {
+                previous = Some(c);
+            }
+        }
+
+        Ok(ints)
+    }
+
+    /// Exactly `N` integers, e.g. `let [x, y] = line.ints_array()?;`.
+    pub fn ints_array<T: FromStr, const N: usize>(&self) -> Result<[T; N], ParseError> {
+        let ints = self.ints()?;
+        let count = ints.len();
+        ints.try_into()
+            .map_err(|_| self.error(format!("expected {N} integers, found {count}")))
+    }
+
+    /// Whitespace separated words.
+    pub fn words(&self) -> impl Iterator<Item = Span<'a>> + '_ {
+        self.text.split_whitespace().map(|x| self.sub(x))
+    }
+
+    /// Splits the span at the first `separator`, without trimming.
+    pub fn split_once(&self, separator: &str) -> Result<(Span<'a>, Span<'a>), ParseError> {
+        let (a, b) = self
+            .text
+            .split_once(separator)
+            .ok_or_else(|| self.error(format!("expected \"{separator}\"")))?;
+
+        Ok((self.sub(a), self.sub(b)))
+    }
+
+    /// Trimmed key and value around the first `separator`, e.g. `"a -> b"` with `"->"`.
+    pub fn key_value(&self, separator: &str) -> Result<(Span<'a>, Span<'a>), ParseError> {
+        let (key, value) = self.split_once(separator)?;
+        Ok((key.trim(), value.trim()))
+    }
+
+    /// Trimmed, non-empty items separated by `separator`.
+    pub fn list(&self, separator: &str) -> Vec<Span<'a>> {
+        self.text
+            .split(separator)
+            .map(|x| self.sub(x).trim())
+            .filter(|x| !x.text.is_empty())
+            .collect()
+    }
+
+    pub fn comma_list(&self) -> Vec<Span<'a>> {
+        self.list(",")
+    }
+
+    /// Parses the `"Label: a, b"` format into the label and its comma separated items.
+    pub fn labeled_list(&self) -> Result<(Span<'a>, Vec<Span<'a>>), ParseError> {
+        let (label, items) = self.split_once(":")?;
+        Ok((label.trim(), items.comma_list()))
+    }
+}
+
+impl Display for Span<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.text)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// An error which can be returned when parsing a [`Span`] fails.
+/// Solutions that return `Result<T, ParseError>` print it instead of an answer.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    pub day: Day,
+    pub line: usize,
+    pub column: usize,
+    /// The offending text, up to the end of its line.
+    pub text: String,
+    pub message: String,
+}
+
+impl Error for ParseError {}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "day {}, line {}, column {}: {}, got \"{}\"",
+            self.day, self.line, self.column, self.message, self.text
+        )
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{input, ParseError};
+    use crate::day;
+
+    #[test]
+    fn extracts_ints() {
+        let line = input(day!(14), "p=0,4 v=3,-3 1-3 x-2");
+        assert_eq!(line.ints::<i32>(), Ok(vec![0, 4, 3, -3, 1, 3, 2]));
+        assert_eq!(line.ints_array::<i64, 7>().unwrap()[3], -3);
+
+        let error = line.ints_array::<i64, 2>().unwrap_err();
+        assert_eq!(error.message, "expected 2 integers, found 7");
+
+        let error = input(day!(1), "3   300").ints::<u8>().unwrap_err();
+        assert_eq!((error.column, error.text.as_str()), (5, "300"));
+    }
+
+    #[test]
+    fn locates_sections_and_lines() {
+        let text = "\nRegister A: 729\nRegister B: 0\n\n\nProgram: 0,1,5,4\n";
+        let sections = input(day!(17), text).sections();
+
+        assert_eq!(sections.len(), 2);
+        assert_eq!(sections[0].text(), "Register A: 729\nRegister B: 0");
+        assert_eq!(sections[1].line(), 6);
+
+        let second = sections[0].lines().nth(1).unwrap();
+        assert_eq!((second.line(), second.column()), (3, 1));
+
+        let (label, items) = sections[1].labeled_list().unwrap();
+        assert_eq!(label.text(), "Program");
+        assert_eq!(items[2].text(), "5");
+        assert_eq!((items[2].line(), items[2].column()), (6, 14));
+    }
+
+    #[test]
+    fn reports_errors_with_location() {
+        let text = "47|53\n97|x3\n";
+        let rules: Result<Vec<(u32, u32)>, ParseError> = input(day!(5), text)
+            .lines()
+            .map(|line| {
+                let (a, b) = line.key_value("|")?;
+                Ok((a.parse()?, b.parse()?))
+            })
+            .collect();
+
+        let error = rules.unwrap_err();
+        assert_eq!((error.line, error.column), (2, 4));
+        assert_eq!(
+            error.to_string(),
+            "day 05, line 2, column 4: expected u32, got \"x3\""
+        );
+
+        let error = input(day!(5), "a b").split_once(",").unwrap_err();
+        assert_eq!(error.message, "expected \",\"");
+    }
+
+    #[test]
+    fn splits_lists() {
+        let line = input(day!(19), "r, wr, b, , g");
+        let items: Vec<&str> = line.comma_list().iter().map(|x| x.text()).collect();
+        assert_eq!(items, vec!["r", "wr", "b", "g"]);
+        assert_eq!(line.words().count(), 5);
+    }
+}
@@ -0,0 +1,367 @@
+/// Shortest path searches over any state type: BFS for unit costs, Dijkstra and A* for weighted edges.
+/// Every search records all predecessors that reach a state at its shortest distance,
+/// so that every shortest path can be recovered from the result, not just one of them.
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+/// Result of a search from a single start state.
+///
+/// ```
+/// # use advent_of_code::pathfinding::bfs;
+/// let paths = bfs(0, |x| [x + 1, x * 2], |x| *x == 10);
+/// assert_eq!(paths.distance(&10), Some(4));
+/// assert_eq!(paths.path_to(&10).unwrap().len(), 5);
+/// ```
+#[derive(Clone, Debug)]
+pub struct ShortestPaths<S, C> {
+    distances: HashMap<S, C>,
+    predecessors: HashMap<S, Vec<S>>,
+    goals: Vec<S>,
+}
+
+impl<S: Clone + Eq + Hash, C: Copy + Ord> ShortestPaths<S, C> {
+    fn new(start: S, zero: C) -> Self {
+        ShortestPaths {
+            distances: HashMap::from([(start, zero)]),
+            predecessors: HashMap::new(),
+            goals: Vec::new(),
+        }
+    }
+
+    /// Records an edge from `from` to `to` that reaches `to` at `cost`.
+    /// Returns `true` if this is a new shortest distance and `to` has to be expanded.
+    fn relax(&mut self, from: &S, to: S, cost: C) -> bool {
+        match self.distances.get(&to).map(|x| cost.cmp(x)) {
+            Some(Ordering::Greater) => false,
+            Some(Ordering::Equal) => {
+                self.predecessors.entry(to).or_default().push(from.clone());
+                false
+            }
+            Some(Ordering::Less) | None => {
+                self.distances.insert(to.clone(), cost);
+                self.predecessors.insert(to, vec![from.clone()]);
+                true
+            }
+        }
+    }
+
+    /// Shortest distances of every state that was reached.
+    /// States reached after the first goal may not have their final distance yet.
+    pub fn distances(&self) -> &HashMap<S, C> {
+        &self.distances
+    }
+
+    pub fn distance(&self, state: &S) -> Option<C> {
+        self.distances.get(state).copied()
+    }
+
+    /// Goal states reached at the shortest distance of any goal.
+    pub fn goals(&self) -> &[S] {
+        &self.goals
+    }
+
+    /// Distance of the nearest goal.
+    pub fn goal_distance(&self) -> Option<C> {
+        self.goals.first().and_then(|x| self.distance(x))
+    }
+
+    /// All states that precede `state` on one of its shortest paths.
+    pub fn predecessors(&self, state: &S) -> &[S] {
+        self.predecessors.get(state).map_or(&[], Vec::as_slice)
+    }
+
+    /// One shortest path from the start to `target`, including both ends.
+    pub fn path_to(&self, target: &S) -> Option<Vec<S>> {
+        self.distances.get(target)?;
+
+        let mut path = vec![target.clone()];
+        while let Some(previous) = self.predecessors(path.last().unwrap()).first() {
+            path.push(previous.clone());
+        }
+        path.reverse();
+
+        Some(path)
+    }
+
+    /// One shortest path from the start to the nearest goal.
+    pub fn goal_path(&self) -> Option<Vec<S>> {
+        self.path_to(self.goals.first()?)
+    }
+
+    /// Every state on any shortest path from the start to one of `targets`.
+    pub fn nodes_on_paths<'a>(&'a self, targets: impl IntoIterator<Item = &'a S>) -> HashSet<S> {
+        let mut stack: Vec<&S> = targets
+            .into_iter()
+            .filter(|x| self.distances.contains_key(x))
+            .collect();
+        let mut nodes: HashSet<S> = stack.iter().map(|x| (*x).clone()).collect();
+
+        while let Some(state) = stack.pop() {
+            for previous in self.predecessors(state) {
+                if nodes.insert(previous.clone()) {
+                    stack.push(previous);
+                }
+            }
+        }
+
+        nodes
+    }
+
+    /// Every state on any shortest path from the start to the nearest goals.
+    pub fn nodes_on_goal_paths(&self) -> HashSet<S> {
+        self.nodes_on_paths(&self.goals)
+    }
+
+    /// Number of distinct shortest paths from the start to one of `targets`.
+    /// Requires edge costs to be positive, so that predecessors are always closer to the start.
+    pub fn count_paths<'a>(&'a self, targets: impl IntoIterator<Item = &'a S>) -> u64 {
+        let targets: Vec<&S> = targets.into_iter().collect();
+
+        let mut nodes: Vec<S> = self
+            .nodes_on_paths(targets.iter().copied())
+            .into_iter()
+            .collect();
+        nodes.sort_by_key(|x| self.distances[x]);
+
+        let mut counts: HashMap<&S, u64> = HashMap::with_capacity(nodes.len());
+        for state in &nodes {
+            let previous = self.predecessors(state);
+            let count = if previous.is_empty() {
+                1
+            } else {
+                previous.iter().map(|x| counts[x]).sum()
+            };
+            counts.insert(state, count);
+        }
+
+        targets.iter().filter_map(|x| counts.get(x)).sum()
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Breadth-first search where every edge costs 1.
+/// `neighbours` lists the states reachable from a state. The search stops once all goals at the
+/// shortest goal distance are found; pass `|_| false` as `is_goal` to explore everything reachable.
+pub fn bfs<S, I>(
+    start: S,
+    mut neighbours: impl FnMut(&S) -> I,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> ShortestPaths<S, usize>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut paths = ShortestPaths::new(start.clone(), 0);
+    let mut queue = VecDeque::from([(start, 0)]);
+    let mut goal_distance = None;
+
+    while let Some((state, distance)) = queue.pop_front() {
+        if goal_distance.is_some_and(|x| distance > x) {
+            break;
+        }
+
+        if is_goal(&state) {
+            goal_distance = Some(distance);
+            paths.goals.push(state);
+            continue;
+        }
+
+        for next in neighbours(&state) {
+            if paths.relax(&state, next.clone(), distance + 1) {
+                queue.push_back((next, distance + 1));
+            }
+        }
+    }
+
+    paths
+}
+
+/// Dijkstra's algorithm. `successors` lists the states reachable from a state with the cost of each edge.
+/// Edge costs must be positive. The cost type needs a zero value as its `Default`, e.g. any integer type.
+pub fn dijkstra<S, C, I>(
+    start: S,
+    successors: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+) -> ShortestPaths<S, C>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (S, C)>,
+{
+    astar(start, successors, |_| C::default(), is_goal)
+}
+
+/// A* search. `heuristic` estimates the remaining cost to a goal. It must never overestimate it,
+/// and must be consistent for the predecessors of every shortest path to be complete.
+pub fn astar<S, C, I>(
+    start: S,
+    mut successors: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> C,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> ShortestPaths<S, C>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (S, C)>,
+{
+    let mut paths = ShortestPaths::new(start.clone(), C::default());
+    let mut heap = BinaryHeap::from([Entry {
+        priority: heuristic(&start),
+        cost: C::default(),
+        state: start,
+    }]);
+    let mut goal_distance = None;
+
+    while let Some(Entry {
+        priority,
+        cost,
+        state,
+    }) = heap.pop()
+    {
+        if goal_distance.is_some_and(|x| priority > x) {
+            break;
+        }
+
+        // skip entries that were superseded by a shorter path.
+        if paths.distances[&state] < cost {
+            continue;
+        }
+
+        if is_goal(&state) {
+            goal_distance = Some(cost);
+            paths.goals.push(state);
+            continue;
+        }
+
+        for (next, step) in successors(&state) {
+            let next_cost = cost + step;
+            if paths.relax(&state, next.clone(), next_cost) {
+                heap.push(Entry {
+                    priority: next_cost + heuristic(&next),
+                    cost: next_cost,
+                    state: next,
+                });
+            }
+        }
+    }
+
+    paths
+}
+
+/// Heap entry that orders by lowest priority first, independent of the state.
+struct Entry<S, C> {
+    priority: C,
+    cost: C,
+    state: S,
+}
+
+impl<S, C: Ord> Ord for Entry<S, C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other
+            .priority
+            .cmp(&self.priority)
+            .then_with(|| self.cost.cmp(&other.cost))
+    }
+}
+
+impl<S, C: Ord> PartialOrd for Entry<S, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<S, C: Ord> PartialEq for Entry<S, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<S, C: Ord> Eq for Entry<S, C> {}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{astar, bfs, dijkstra};
+    use crate::geometry::{Direction, Point};
+    use crate::grid::Grid;
+
+    fn get_mock_maze() -> Grid<char> {
+        Grid::parse(
+            "#######\n#S....#\n#.#.#.#\n#.....#\n#.#.#.#\n#....E#\n#######\n",
+            |c| c,
+        )
+    }
+
+    fn open_neighbours(grid: &Grid<char>, point: Point) -> Vec<Point> {
+        Direction::iter()
+            .map(|x| point.step(x))
+            .filter(|x| grid.get_point(*x).is_some_and(|c| *c != '#'))
+            .collect()
+    }
+
+    #[test]
+    fn finds_all_shortest_paths_with_bfs() {
+        let grid = get_mock_maze();
+        let start: Point = grid.find(|c| *c == 'S').unwrap().into();
+        let end: Point = grid.find(|c| *c == 'E').unwrap().into();
+
+        let paths = bfs(start, |x| open_neighbours(&grid, *x), |x| *x == end);
+
+        assert_eq!(paths.goal_distance(), Some(8));
+        assert_eq!(paths.goal_path().unwrap().len(), 9);
+        assert_eq!(paths.count_paths([&end]), 6);
+        assert_eq!(paths.nodes_on_goal_paths().len(), 21);
+    }
+
+    #[test]
+    fn finds_cheapest_paths_with_turns() {
+        // day 16 style: moving forward costs 1, turning costs 1000.
+        let grid = get_mock_maze();
+        let start = (
+            Point::from(grid.find(|c| *c == 'S').unwrap()),
+            Direction::Right,
+        );
+        let end: Point = grid.find(|c| *c == 'E').unwrap().into();
+
+        let successors = |(point, direction): &(Point, Direction)| {
+            let forward = point.step(*direction);
+            let mut next = vec![
+                ((*point, direction.turn_left()), 1000_u64),
+                ((*point, direction.turn_right()), 1000),
+            ];
+            if grid.get_point(forward).is_some_and(|c| *c != '#') {
+                next.push(((forward, *direction), 1));
+            }
+            next
+        };
+
+        let paths = dijkstra(start, &successors, |(point, _)| *point == end);
+        assert_eq!(paths.goal_distance(), Some(1008));
+
+        let tiles: std::collections::HashSet<Point> = paths
+            .nodes_on_goal_paths()
+            .into_iter()
+            .map(|(point, _)| point)
+            .collect();
+        assert_eq!(tiles.len(), 9);
+
+        let heuristic = |(point, _): &(Point, Direction)| point.manhattan_distance(end);
+        let guided = astar(start, &successors, heuristic, |(point, _)| *point == end);
+        assert_eq!(guided.goal_distance(), Some(1008));
+        assert_eq!(guided.nodes_on_goal_paths(), paths.nodes_on_goal_paths());
+    }
+
+    #[test]
+    fn explores_everything_without_goal() {
+        let paths = bfs(0_u32, |x| (*x < 5).then_some(x + 1), |_| false);
+        assert!(paths.goals().is_empty());
+        assert_eq!(paths.distance(&5), Some(5));
+        assert_eq!(paths.path_to(&3), Some(vec![0, 1, 2, 3]));
+        assert_eq!(paths.path_to(&6), None);
+        assert!(paths.predecessors(&0).is_empty());
+    }
+}
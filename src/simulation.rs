@@ -0,0 +1,365 @@
+/// Plays step-by-step simulations in the terminal, for debugging the logic of a day visually.
+/// The player redraws the current frame in place and keeps the most recent frames, so it can also step back.
+///
+/// | Key | Action |
+/// | --- | --- |
+/// | `space` | pause or resume |
+/// | `→` / `l` | step forward |
+/// | `←` / `h` | step back |
+/// | `+` / `-` | faster / slower |
+/// | `q` / `esc` | quit |
+use std::collections::VecDeque;
+use std::io::{self, Write};
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::thread;
+use std::time::Duration;
+
+use console::{Key, Term};
+
+/// A simulation that advances in discrete steps.
+pub trait Simulation {
+    /// Advances the simulation by one step. Returns `false` if it has already finished and nothing changed.
+    fn step(&mut self) -> bool;
+
+    /// Draws the current state, e.g. with the `Display` implementation of a [`Grid`](crate::grid::Grid).
+    fn render(&self) -> String;
+}
+
+/// Plays a [`Simulation`] in the terminal.
+///
+/// ```no_run
+/// # use advent_of_code::simulation::{Player, Simulation};
+/// struct Counter(u32);
+///
+/// impl Simulation for Counter {
+///     fn step(&mut self) -> bool {
+///         self.0 += 1;
+///         self.0 <= 10
+///     }
+///
+///     fn render(&self) -> String {
+///         "#".repeat(self.0 as usize)
+///     }
+/// }
+///
+/// Player::new().play(&mut Counter(0))?;
+/// # Ok::<(), std::io::Error>(())
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct Player {
+    delay: Duration,
+    paused: bool,
+    history: usize,
+}
+
+impl Default for Player {
+    fn default() -> Self {
+        Player::new()
+    }
+}
+
+const ANSI_ENTER_SCREEN: &str = "\x1b[?1049h\x1b[?25l";
+const ANSI_LEAVE_SCREEN: &str = "\x1b[?25h\x1b[?1049l";
+const ANSI_HOME: &str = "\x1b[H";
+const ANSI_CLEAR_LINE: &str = "\x1b[K";
+const ANSI_CLEAR_BELOW: &str = "\x1b[J";
+
+const MIN_DELAY: Duration = Duration::from_millis(1);
+const MAX_DELAY: Duration = Duration::from_secs(5);
+
+impl Player {
+    /// Plays 10 steps per second, starting right away.
+    pub const fn new() -> Self {
+        Player {
+            delay: Duration::from_millis(100),
+            paused: false,
+            history: 10_000,
+        }
+    }
+
+    /// Time between two steps while playing.
+    #[must_use]
+    pub const fn delay(mut self, delay: Duration) -> Self {
+        self.delay = delay;
+        self
+    }
+
+    /// Starts paused on the first frame.
+    #[must_use]
+    pub const fn paused(mut self, paused: bool) -> Self {
+        self.paused = paused;
+        self
+    }
+
+    /// Number of frames kept for stepping back, 10 000 by default. Older frames are dropped,
+    /// so that long simulations like the robots of day 14 don't keep every frame in memory.
+    #[must_use]
+    pub const fn history(mut self, frames: usize) -> Self {
+        self.history = frames;
+        self
+    }
+
+    /// Plays `simulation` until quit with `q`. Fails if stdout is not a terminal.
+    pub fn play(&self, simulation: &mut impl Simulation) -> io::Result<()> {
+        let term = Term::stdout();
+        if !term.is_term() {
+            return Err(io::Error::new(
+                io::ErrorKind::Unsupported,
+                "the simulation player needs a terminal",
+            ));
+        }
+
+        // keys are read on a separate thread, so that playing can wait for a key or the next step.
+        let (sender, receiver) = mpsc::channel();
+        thread::spawn(move || loop {
+            let command = term.read_key().map_or(Some(Command::Quit), |x| command(&x));
+            if let Some(command) = command {
+                if sender.send(command).is_err() || command == Command::Quit {
+                    break;
+                }
+            }
+        });
+
+        let mut screen = Screen::enter()?;
+        let mut playback = Playback::new(simulation, self.history);
+        let mut paused = self.paused;
+        let mut delay = self.delay;
+
+        loop {
+            draw(&mut screen.stdout, &playback, paused, delay)?;
+
+            let received = if paused || playback.is_at_end() {
+                receiver.recv().map_err(|_| RecvTimeoutError::Disconnected)
+            } else {
+                receiver.recv_timeout(delay)
+            };
+
+            match received {
+                Err(RecvTimeoutError::Timeout) => {
+                    playback.forward(simulation);
+                }
+                Err(RecvTimeoutError::Disconnected) | Ok(Command::Quit) => break,
+                Ok(Command::TogglePause) => paused = !paused,
+                Ok(Command::Forward) => {
+                    paused = true;
+                    playback.forward(simulation);
+                }
+                Ok(Command::Back) => {
+                    paused = true;
+                    playback.back();
+                }
+                Ok(Command::Faster) => delay = (delay / 2).max(MIN_DELAY),
+                Ok(Command::Slower) => delay = (delay * 2).min(MAX_DELAY),
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// The alternate screen with a hidden cursor. The terminal is restored when dropped, also on errors.
+struct Screen {
+    stdout: io::Stdout,
+}
+
+impl Screen {
+    fn enter() -> io::Result<Self> {
+        let mut stdout = io::stdout();
+        write!(stdout, "{ANSI_ENTER_SCREEN}")?;
+        Ok(Screen { stdout })
+    }
+}
+
+impl Drop for Screen {
+    fn drop(&mut self) {
+        let _ = write!(self.stdout, "{ANSI_LEAVE_SCREEN}");
+        let _ = self.stdout.flush();
+    }
+}
+
+fn draw(
+    stdout: &mut impl Write,
+    playback: &Playback,
+    paused: bool,
+    delay: Duration,
+) -> io::Result<()> {
+    write!(stdout, "{ANSI_HOME}")?;
+
+    for line in playback.frame().lines() {
+        write!(stdout, "{line}{ANSI_CLEAR_LINE}\r\n")?;
+    }
+
+    let state = match (paused, playback.is_at_end()) {
+        (_, true) => "finished",
+        (true, false) => "paused",
+        (false, false) => "playing",
+    };
+
+    write!(
+        stdout,
+        "\r\nstep {} of {} · {state} · {delay:?} per step{ANSI_CLEAR_LINE}\r\nspace: pause · ←/→: step · +/-: speed · q: quit{ANSI_CLEAR_LINE}\r\n{ANSI_CLEAR_BELOW}",
+        playback.step(),
+        playback.dropped + playback.frames.len() - 1,
+    )?;
+
+    stdout.flush()
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Command {
+    TogglePause,
+    Forward,
+    Back,
+    Faster,
+    Slower,
+    Quit,
+}
+
+fn command(key: &Key) -> Option<Command> {
+    match key {
+        Key::Char(' ') => Some(Command::TogglePause),
+        Key::ArrowRight | Key::Char('l' | 'n') => Some(Command::Forward),
+        Key::ArrowLeft | Key::Char('h' | 'p') => Some(Command::Back),
+        Key::Char('+' | '=') => Some(Command::Faster),
+        Key::Char('-') => Some(Command::Slower),
+        Key::Char('q') | Key::Escape | Key::CtrlC => Some(Command::Quit),
+        _ => None,
+    }
+}
+
+/// The most recent frames and the one that is shown.
+struct Playback {
+    frames: VecDeque<String>,
+    /// Maximum number of frames that are kept.
+    limit: usize,
+    /// Number of frames that were dropped from the front.
+    dropped: usize,
+    index: usize,
+    finished: bool,
+}
+
+impl Playback {
+    fn new(simulation: &impl Simulation, limit: usize) -> Self {
+        Playback {
+            frames: VecDeque::from([simulation.render()]),
+            limit: limit.max(1),
+            dropped: 0,
+            index: 0,
+            finished: false,
+        }
+    }
+
+    fn frame(&self) -> &str {
+        &self.frames[self.index]
+    }
+
+    /// Number of steps from the start of the simulation to the shown frame.
+    fn step(&self) -> usize {
+        self.dropped + self.index
+    }
+
+    fn is_at_end(&self) -> bool {
+        self.finished && self.index == self.frames.len() - 1
+    }
+
+    /// Shows the next frame, advancing the simulation if it was not rendered yet.
+    fn forward(&mut self, simulation: &mut impl Simulation) {
+        if self.index + 1 < self.frames.len() {
+            self.index += 1;
+        } else if !self.finished {
+            if simulation.step() {
+                self.frames.push_back(simulation.render());
+
+                if self.frames.len() > self.limit {
+                    self.frames.pop_front();
+                    self.dropped += 1;
+                } else {
+                    self.index += 1;
+                }
+            } else {
+                self.finished = true;
+            }
+        }
+    }
+
+    fn back(&mut self) {
+        self.index = self.index.saturating_sub(1);
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use console::Key;
+
+    use super::{command, Command, Playback, Simulation};
+
+    struct Counter(u32);
+
+    impl Simulation for Counter {
+        fn step(&mut self) -> bool {
+            if self.0 == 3 {
+                return false;
+            }
+            self.0 += 1;
+            true
+        }
+
+        fn render(&self) -> String {
+            self.0.to_string()
+        }
+    }
+
+    #[test]
+    fn steps_forward_and_back() {
+        let mut counter = Counter(0);
+        let mut playback = Playback::new(&counter, 10);
+
+        playback.forward(&mut counter);
+        playback.forward(&mut counter);
+        assert_eq!(playback.frame(), "2");
+
+        playback.back();
+        playback.back();
+        playback.back();
+        assert_eq!(playback.frame(), "0");
+
+        // replays frames without stepping the simulation again.
+        playback.forward(&mut counter);
+        assert_eq!((playback.frame(), counter.0), ("1", 2));
+
+        for _ in 0..5 {
+            playback.forward(&mut counter);
+        }
+        assert_eq!(playback.frame(), "3");
+        assert!(playback.is_at_end());
+    }
+
+    #[test]
+    fn drops_old_frames() {
+        let mut counter = Counter(0);
+        let mut playback = Playback::new(&counter, 2);
+
+        for _ in 0..3 {
+            playback.forward(&mut counter);
+        }
+        assert_eq!((playback.frame(), playback.step()), ("3", 3));
+
+        playback.back();
+        playback.back();
+        assert_eq!((playback.frame(), playback.step()), ("2", 2));
+    }
+
+    #[test]
+    fn maps_keys_to_commands() {
+        assert_eq!(command(&Key::Char(' ')), Some(Command::TogglePause));
+        assert_eq!(command(&Key::ArrowLeft), Some(Command::Back));
+        assert_eq!(command(&Key::Char('l')), Some(Command::Forward));
+        assert_eq!(command(&Key::Char('-')), Some(Command::Slower));
+        assert_eq!(command(&Key::Escape), Some(Command::Quit));
+        assert_eq!(command(&Key::Char('x')), None);
+    }
+}
@@ -0,0 +1,342 @@
+use std::{collections::HashMap, fs, io::Error, str::FromStr};
+use tinyjson::JsonValue;
+
+use crate::template::Day;
+
+static ANSWERS_FILE_PATH: &str = "./data/answers.json";
+
+/// Outcome of submitting an answer, as reported by the advent of code website.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SubmitOutcome {
+    Correct,
+    Incorrect,
+    AlreadySolved,
+    RateLimited,
+    Unknown,
+}
+
+impl SubmitOutcome {
+    /// Interprets the message printed by `aoc submit`.
+    pub fn parse(output: &str) -> Self {
+        if output.contains("That's the right answer") {
+            Self::Correct
+        } else if output.contains("That's not the right answer") {
+            Self::Incorrect
+        } else if output.contains("Did you already complete it?") {
+            Self::AlreadySolved
+        } else if output.contains("You gave an answer too recently") {
+            Self::RateLimited
+        } else {
+            Self::Unknown
+        }
+    }
+}
+
+/// Known progress for a single part of a day.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct PartAnswer {
+    pub solved: bool,
+    pub answer: Option<String>,
+    pub incorrect: Vec<String>,
+}
+
+/// Known progress for a single day.
+#[derive(Clone, Debug)]
+pub struct DayAnswers {
+    pub day: Day,
+    pub part_1: PartAnswer,
+    pub part_2: PartAnswer,
+}
+
+/// Represents answers and submission outcomes for a set of days.
+/// Can be serialized from / to JSON.
+#[derive(Clone, Debug, Default)]
+pub struct Answers {
+    pub data: Vec<DayAnswers>,
+}
+
+impl Answers {
+    /// Dehydrate answers to a JSON file.
+    pub fn store_file(&self) -> Result<(), Error> {
+        let json = JsonValue::from(self.clone());
+        let mut file = fs::File::create(ANSWERS_FILE_PATH)?;
+        json.format_to(&mut file)
+    }
+
+    /// Rehydrate answers from a JSON file. If not present, returns empty answers.
+    pub fn read_from_file() -> Self {
+        fs::read_to_string(ANSWERS_FILE_PATH)
+            .ok()
+            .and_then(|s| match Answers::try_from(s) {
+                Ok(answers) => Some(answers),
+                Err(e) => {
+                    eprintln!("{e}");
+                    None
+                }
+            })
+            .unwrap_or_default()
+    }
+
+    pub fn get(&self, day: Day, part: u8) -> Option<&PartAnswer> {
+        self.data
+            .iter()
+            .find(|d| d.day == day)
+            .map(|d| if part == 1 { &d.part_1 } else { &d.part_2 })
+    }
+
+    pub fn is_solved(&self, day: Day, part: u8) -> bool {
+        self.get(day, part).is_some_and(|p| p.solved)
+    }
+
+    /// Record the outcome of a submission. Outcomes that carry no information about the answer are ignored.
+    /// This includes [`SubmitOutcome::AlreadySolved`], which the website also reports when part two is still locked.
+    pub fn record(&mut self, day: Day, part: u8, answer: &str, outcome: SubmitOutcome) {
+        let index = match self.data.iter().position(|d| d.day == day) {
+            Some(index) => index,
+            None => {
+                self.data.push(DayAnswers {
+                    day,
+                    part_1: PartAnswer::default(),
+                    part_2: PartAnswer::default(),
+                });
+                self.data.sort_unstable_by_key(|d| d.day);
+                self.data.iter().position(|d| d.day == day).unwrap()
+            }
+        };
+
+        let entry = &mut self.data[index];
+        let part = if part == 1 {
+            &mut entry.part_1
+        } else {
+            &mut entry.part_2
+        };
+
+        match outcome {
+            SubmitOutcome::Correct => {
+                part.solved = true;
+                part.answer = Some(answer.into());
+            }
+            SubmitOutcome::Incorrect => {
+                if !part.incorrect.iter().any(|x| x == answer) {
+                    part.incorrect.push(answer.into());
+                }
+            }
+            SubmitOutcome::AlreadySolved | SubmitOutcome::RateLimited | SubmitOutcome::Unknown => {}
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<Answers> for JsonValue {
+    fn from(value: Answers) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert(
+            "data".into(),
+            JsonValue::Array(value.data.iter().map(JsonValue::from).collect()),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<String> for Answers {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let json = JsonValue::from_str(&value).or(Err("not valid JSON file."))?;
+
+        let json_data = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("expected JSON document to be an object.")?
+            .get("data")
+            .ok_or("expected JSON document to have key `data`.")?
+            .get::<Vec<JsonValue>>()
+            .ok_or("expected `json.data` to be an array.")?;
+
+        Ok(Answers {
+            data: json_data
+                .iter()
+                .map(DayAnswers::try_from)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&DayAnswers> for JsonValue {
+    fn from(value: &DayAnswers) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert("day".into(), JsonValue::String(value.day.to_string()));
+        map.insert("part_1".into(), JsonValue::from(&value.part_1));
+        map.insert("part_2".into(), JsonValue::from(&value.part_2));
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for DayAnswers {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected answers to be a JSON object.")?;
+
+        let day = json
+            .get("day")
+            .and_then(|v| v.get::<String>())
+            .and_then(|day| Day::from_str(day).ok())
+            .ok_or("Expected answers.day to be a Day struct.")?;
+
+        let part_1 = json
+            .get("part_1")
+            .ok_or("Expected answers.part_1 to be an object.")
+            .and_then(PartAnswer::try_from)?;
+
+        let part_2 = json
+            .get("part_2")
+            .ok_or("Expected answers.part_2 to be an object.")
+            .and_then(PartAnswer::try_from)?;
+
+        Ok(DayAnswers {
+            day,
+            part_1,
+            part_2,
+        })
+    }
+}
+
+impl From<&PartAnswer> for JsonValue {
+    fn from(value: &PartAnswer) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert("solved".into(), JsonValue::Boolean(value.solved));
+        map.insert(
+            "answer".into(),
+            match &value.answer {
+                Some(x) => JsonValue::String(x.clone()),
+                None => JsonValue::Null,
+            },
+        );
+        map.insert(
+            "incorrect".into(),
+            JsonValue::Array(
+                value
+                    .incorrect
+                    .iter()
+                    .map(|x| JsonValue::String(x.clone()))
+                    .collect(),
+            ),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for PartAnswer {
+    type Error = &'static str;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected part answer to be a JSON object.")?;
+
+        let solved = json
+            .get("solved")
+            .and_then(|v| v.get::<bool>().copied())
+            .ok_or("Expected part.solved to be a boolean.")?;
+
+        let answer = json
+            .get("answer")
+            .map(|v| if v.is_null() { None } else { v.get::<String>() })
+            .ok_or("Expected part.answer to be null or string.")?;
+
+        let incorrect = json
+            .get("incorrect")
+            .and_then(|v| v.get::<Vec<JsonValue>>())
+            .ok_or("Expected part.incorrect to be an array.")?
+            .iter()
+            .map(|v| v.get::<String>().cloned())
+            .collect::<Option<_>>()
+            .ok_or("Expected part.incorrect to contain strings.")?;
+
+        Ok(PartAnswer {
+            solved,
+            answer: answer.cloned(),
+            incorrect,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Answers, SubmitOutcome};
+    use crate::day;
+
+    #[test]
+    fn parses_submit_outcomes() {
+        assert_eq!(
+            SubmitOutcome::parse("That's the right answer! You are one gold star closer."),
+            SubmitOutcome::Correct
+        );
+        assert_eq!(
+            SubmitOutcome::parse("That's not the right answer; your answer is too low."),
+            SubmitOutcome::Incorrect
+        );
+        assert_eq!(
+            SubmitOutcome::parse(
+                "You don't seem to be solving the right level.  Did you already complete it?"
+            ),
+            SubmitOutcome::AlreadySolved
+        );
+        assert_eq!(SubmitOutcome::parse(""), SubmitOutcome::Unknown);
+    }
+
+    #[test]
+    fn records_outcomes() {
+        let mut answers = Answers::default();
+        answers.record(day!(3), 1, "41", SubmitOutcome::Incorrect);
+        answers.record(day!(3), 1, "41", SubmitOutcome::Incorrect);
+        answers.record(day!(3), 1, "42", SubmitOutcome::Correct);
+        answers.record(day!(1), 2, "7", SubmitOutcome::RateLimited);
+        answers.record(day!(3), 2, "9", SubmitOutcome::AlreadySolved);
+
+        assert_eq!(answers.data.len(), 2);
+        assert_eq!(answers.data[0].day, day!(1));
+        assert!(!answers.is_solved(day!(1), 2));
+
+        let part = answers.get(day!(3), 1).unwrap();
+        assert!(part.solved);
+        assert_eq!(part.answer, Some("42".into()));
+        assert_eq!(part.incorrect, vec!["41".to_string()]);
+        assert!(!answers.is_solved(day!(3), 2));
+    }
+
+    #[test]
+    fn roundtrips_json() {
+        let mut answers = Answers::default();
+        answers.record(day!(2), 1, "42", SubmitOutcome::Correct);
+        answers.record(day!(2), 2, "1", SubmitOutcome::Incorrect);
+
+        let json = tinyjson::JsonValue::from(answers.clone())
+            .stringify()
+            .unwrap();
+        let parsed = Answers::try_from(json).unwrap();
+
+        assert_eq!(parsed.data.len(), 1);
+        assert_eq!(parsed.data[0].part_1, answers.data[0].part_1);
+        assert_eq!(parsed.data[0].part_2, answers.data[0].part_2);
+    }
+
+    #[test]
+    #[should_panic]
+    fn panics_for_malformed_answers() {
+        Answers::try_from(r#"{ "data": [{ "day": "01", "part_1": {} }] }"#.to_string()).unwrap();
+    }
+}
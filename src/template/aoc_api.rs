@@ -0,0 +1,124 @@
+/// Minimal client for the JSON endpoints of the advent of code website that aoc-cli does not expose.
+/// Requests are made with "curl" and authenticated with the same session cookie aoc-cli uses.
+use std::{
+    env,
+    fmt::Display,
+    fs,
+    io::Write,
+    path::PathBuf,
+    process::{Command, Output, Stdio},
+};
+
+use crate::template::Day;
+
+static DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+#[derive(Debug)]
+pub enum AocApiError {
+    SessionNotFound,
+    CommandNotCallable,
+    BadExitStatus(Output),
+}
+
+impl Display for AocApiError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AocApiError::SessionNotFound => write!(
+                f,
+                "no session cookie found in $ADVENT_OF_CODE_SESSION or ~/.adventofcode.session."
+            ),
+            AocApiError::CommandNotCallable => write!(f, "curl could not be called."),
+            AocApiError::BadExitStatus(output) => {
+                write!(
+                    f,
+                    "curl exited with a non-zero status: {}",
+                    String::from_utf8_lossy(&output.stderr).trim()
+                )
+            }
+        }
+    }
+}
+
+/// Client for the advent of code website.
+pub struct AocClient {
+    base_url: String,
+    session: String,
+}
+
+impl AocClient {
+    pub fn new(base_url: &str, session: &str) -> Self {
+        Self {
+            base_url: base_url.trim_end_matches('/').into(),
+            session: session.into(),
+        }
+    }
+
+    /// Creates a client for the website, or for the server set via `AOC_BASE_URL`.
+    pub fn from_env() -> Result<Self, AocApiError> {
+        let base_url = env::var("AOC_BASE_URL").unwrap_or_else(|_| DEFAULT_BASE_URL.into());
+        Ok(Self::new(&base_url, &get_session()?))
+    }
+
+    /// Fetches the JSON document of a private leaderboard.
+    pub fn private_leaderboard(&self, year: u16, id: u32) -> Result<String, AocApiError> {
+        self.get(&format!("/{year}/leaderboard/private/view/{id}.json"))
+    }
+
+    /// Fetches the HTML page of a puzzle. Once part one is solved, it includes part two.
+    pub fn puzzle(&self, year: u16, day: Day) -> Result<String, AocApiError> {
+        self.get(&format!("/{year}/day/{}", day.into_inner()))
+    }
+
+    fn get(&self, path: &str) -> Result<String, AocApiError> {
+        let url = format!("{}{path}", self.base_url);
+
+        let mut child = Command::new("curl")
+            .args(["--silent", "--show-error", "--fail", "--location"])
+            // the cookie is passed as config on stdin, so that it does not show up in the process list.
+            .args(["--config", "-"])
+            .args(["--user-agent", "github.com/fspoettel/advent-of-code-rust"])
+            .arg(&url)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .map_err(|_| AocApiError::CommandNotCallable)?;
+
+        if let Some(mut stdin) = child.stdin.take() {
+            let session = self.session.replace('\\', "\\\\").replace('"', "\\\"");
+            writeln!(stdin, "cookie = \"session={session}\"")
+                .map_err(|_| AocApiError::CommandNotCallable)?;
+        }
+
+        let output = child
+            .wait_with_output()
+            .map_err(|_| AocApiError::CommandNotCallable)?;
+
+        if output.status.success() {
+            Ok(String::from_utf8_lossy(&output.stdout).to_string())
+        } else {
+            Err(AocApiError::BadExitStatus(output))
+        }
+    }
+}
+
+fn get_session() -> Result<String, AocApiError> {
+    if let Ok(session) = env::var("ADVENT_OF_CODE_SESSION") {
+        return Ok(session.trim().to_string());
+    }
+
+    let home = env::var("HOME")
+        .or_else(|_| env::var("USERPROFILE"))
+        .map_err(|_| AocApiError::SessionNotFound)?;
+
+    [
+        PathBuf::from(&home).join(".adventofcode.session"),
+        PathBuf::from(&home)
+            .join(".config")
+            .join("adventofcode.session"),
+    ]
+    .iter()
+    .find_map(|path| fs::read_to_string(path).ok())
+    .map(|session| session.trim().to_string())
+    .ok_or(AocApiError::SessionNotFound)
+}
@@ -77,7 +77,11 @@ pub fn submit(day: Day, part: u8, result: &str) -> Result<Output, AocCommandErro
     let mut args = build_args("submit", &[], day);
     args.push(part.to_string());
     args.push(result.to_string());
-    call_aoc_cli(&args)
+
+    // capture stdout so the caller can inspect the outcome of the submission.
+    let output = call_aoc_cli_with_stdout(&args, Stdio::piped())?;
+    print!("{}", String::from_utf8_lossy(&output.stdout));
+    Ok(output)
 }
 
 fn get_input_path(day: Day) -> String {
@@ -88,7 +92,8 @@ fn get_puzzle_path(day: Day) -> String {
     format!("data/puzzles/{day}.md")
 }
 
-fn get_year() -> Option<u16> {
+/// Returns the year set via `AOC_YEAR`.
+pub fn get_year() -> Option<u16> {
     match std::env::var("AOC_YEAR") {
         Ok(x) => x.parse().ok().or(None),
         Err(_) => None,
@@ -109,10 +114,14 @@ fn build_args(command: &str, args: &[String], day: Day) -> Vec<String> {
 }
 
 fn call_aoc_cli(args: &[String]) -> Result<Output, AocCommandError> {
+    call_aoc_cli_with_stdout(args, Stdio::inherit())
+}
+
+fn call_aoc_cli_with_stdout(args: &[String], stdout: Stdio) -> Result<Output, AocCommandError> {
     // println!("Calling >aoc with: {}", args.join(" "));
     let output = Command::new("aoc")
         .args(args)
-        .stdout(Stdio::inherit())
+        .stdout(stdout)
         .stderr(Stdio::inherit())
         .output()
         .map_err(|_| AocCommandError::CommandNotCallable)?;
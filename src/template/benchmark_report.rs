@@ -0,0 +1,460 @@
+/// Renders stored timings to a self-contained HTML page with SVG charts.
+/// The page does not load any external resources, so it can be opened offline or attached to a review.
+use std::fmt::Write;
+
+use crate::template::timings::{format_nanos, Timing, Timings};
+use crate::template::timings_history::Snapshot;
+
+const CHART_WIDTH: f64 = 760.0;
+const LABEL_WIDTH: f64 = 64.0;
+const VALUE_WIDTH: f64 = 96.0;
+const ROW_HEIGHT: f64 = 24.0;
+const BAR_HEIGHT: f64 = 9.0;
+const AXIS_HEIGHT: f64 = 24.0;
+const HISTORY_HEIGHT: f64 = 320.0;
+const MAX_HISTORY_LABELS: usize = 8;
+
+const COLOR_TOTAL: &str = "#4e79a7";
+const COLOR_PARTS: [&str; 2] = ["#4e79a7", "#f28e2b"];
+const COLOR_GRID: &str = "#d0d7de";
+
+static STYLE: &str = "body { font-family: system-ui, sans-serif; margin: 2rem auto; max-width: 820px; color: #1f2328; }
+h1 { font-size: 1.6rem; }
+h2 { font-size: 1.2rem; margin-top: 2.5rem; }
+svg { display: block; overflow: visible; }
+svg text { font-size: 12px; fill: #1f2328; }
+svg .muted { fill: #656d76; }
+.legend span { display: inline-block; margin-right: 1rem; }
+.legend i { display: inline-block; width: 10px; height: 10px; margin-right: 4px; }
+table { border-collapse: collapse; margin-top: 1rem; }
+th, td { border-bottom: 1px solid #d0d7de; padding: 4px 12px; text-align: right; }
+th:first-child, td:first-child { text-align: left; }";
+
+/// Renders the report page. `history` holds earlier snapshots of the timings, oldest first.
+pub fn render(timings: &Timings, history: &[Snapshot]) -> String {
+    let total_millis = timings.total_millis();
+
+    let mut html = String::new();
+    html.push_str("<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n");
+    html.push_str("<title>Benchmarks</title>\n");
+    let _ = writeln!(html, "<style>\n{STYLE}\n</style>");
+    html.push_str("</head>\n<body>\n<h1>Benchmarks</h1>\n");
+    let _ = writeln!(html, "<p><strong>Total: {total_millis:.2}ms</strong></p>");
+
+    html.push_str("<h2>Runtime per day</h2>\n");
+    html.push_str(&render_day_chart(timings));
+
+    html.push_str("<h2>Parts (log scale)</h2>\n");
+    html.push_str(&render_legend(&[
+        ("Part 1", COLOR_PARTS[0]),
+        ("Part 2", COLOR_PARTS[1]),
+    ]));
+    html.push_str(&render_parts_chart(timings));
+
+    html.push_str("<h2>History (log scale)</h2>\n");
+    html.push_str(&render_history_chart(history));
+
+    html.push_str("<h2>Timings</h2>\n");
+    html.push_str(&render_table(timings));
+
+    html.push_str("</body>\n</html>\n");
+    html
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Horizontal bars with the total runtime of each day, on a linear scale.
+fn render_day_chart(timings: &Timings) -> String {
+    let rows: Vec<&Timing> = timings
+        .data
+        .iter()
+        .filter(|x| x.total_nanos() > 0.0)
+        .collect();
+
+    let Some(max) = rows.iter().map(|x| x.total_nanos()).reduce(f64::max) else {
+        return "<p>No timings stored yet.</p>\n".into();
+    };
+
+    let plot_width = CHART_WIDTH - LABEL_WIDTH - VALUE_WIDTH;
+    #[allow(clippy::cast_precision_loss)]
+    let height = rows.len() as f64 * ROW_HEIGHT;
+
+    let mut svg = open_svg(height);
+
+    for (i, timing) in rows.iter().enumerate() {
+        #[allow(clippy::cast_precision_loss)]
+        let y = i as f64 * ROW_HEIGHT;
+        let width = (timing.total_nanos() / max * plot_width).max(1.0);
+        let value = format_nanos(timing.total_nanos());
+
+        let _ = writeln!(
+            svg,
+            "<text x=\"0\" y=\"{:.1}\">Day {}</text>",
+            y + ROW_HEIGHT * 0.65,
+            timing.day
+        );
+        let _ = writeln!(
+            svg,
+            "<rect x=\"{LABEL_WIDTH}\" y=\"{:.1}\" width=\"{width:.1}\" height=\"{:.1}\" fill=\"{COLOR_TOTAL}\"><title>Day {}: {value}</title></rect>",
+            y + ROW_HEIGHT * 0.2,
+            ROW_HEIGHT * 0.6,
+            timing.day
+        );
+        let _ = writeln!(
+            svg,
+            "<text class=\"muted\" x=\"{:.1}\" y=\"{:.1}\">{value}</text>",
+            LABEL_WIDTH + width + 6.0,
+            y + ROW_HEIGHT * 0.65
+        );
+    }
+
+    svg.push_str("</svg>\n");
+    svg
+}
+
+/// Bars for both parts of each day, on a logarithmic scale so that fast and slow days can be compared.
+fn render_parts_chart(timings: &Timings) -> String {
+    let rows: Vec<(&Timing, [Option<f64>; 2])> = timings
+        .data
+        .iter()
+        .map(|x| (x, [x.part_nanos(1), x.part_nanos(2)]))
+        .filter(|(_, parts)| parts.iter().any(Option::is_some))
+        .collect();
+
+    let values = rows.iter().flat_map(|(_, parts)| parts.iter().flatten());
+
+    let Some(scale) = LogScale::new(values.copied(), CHART_WIDTH - LABEL_WIDTH - VALUE_WIDTH)
+    else {
+        return "<p>No timings stored yet.</p>\n".into();
+    };
+
+    #[allow(clippy::cast_precision_loss)]
+    let height = rows.len() as f64 * ROW_HEIGHT + AXIS_HEIGHT;
+    let mut svg = open_svg(height);
+
+    svg.push_str(&scale.render_grid(LABEL_WIDTH, 0.0, height - AXIS_HEIGHT, true));
+
+    for (i, (timing, parts)) in rows.iter().enumerate() {
+        #[allow(clippy::cast_precision_loss)]
+        let y = i as f64 * ROW_HEIGHT;
+
+        let _ = writeln!(
+            svg,
+            "<text x=\"0\" y=\"{:.1}\">Day {}</text>",
+            y + ROW_HEIGHT * 0.65,
+            timing.day
+        );
+
+        for (part, nanos) in parts.iter().enumerate() {
+            let Some(nanos) = nanos else {
+                continue;
+            };
+
+            #[allow(clippy::cast_precision_loss)]
+            let bar_y = y + 3.0 + part as f64 * (BAR_HEIGHT + 1.0);
+            let width = scale.position(*nanos).max(1.0);
+            let value = format_nanos(*nanos);
+
+            let _ = writeln!(
+                svg,
+                "<rect x=\"{LABEL_WIDTH}\" y=\"{bar_y:.1}\" width=\"{width:.1}\" height=\"{BAR_HEIGHT}\" fill=\"{}\"><title>Day {} part {}: {value}</title></rect>",
+                COLOR_PARTS[part],
+                timing.day,
+                part + 1
+            );
+            let _ = writeln!(
+                svg,
+                "<text class=\"muted\" x=\"{:.1}\" y=\"{:.1}\" style=\"font-size: 10px\">{value}</text>",
+                LABEL_WIDTH + width + 6.0,
+                bar_y + BAR_HEIGHT - 1.0
+            );
+        }
+    }
+
+    svg.push_str("</svg>\n");
+    svg
+}
+
+/// One line per day and one for the total, across the snapshots of the timings.
+fn render_history_chart(history: &[Snapshot]) -> String {
+    if history.len() < 2 {
+        return "<p>The history is shown once <code>data/timings.json</code> has been committed with different timings.</p>\n".into();
+    }
+
+    let totals: Vec<f64> = history
+        .iter()
+        .map(|x| x.timings.data.iter().map(Timing::total_nanos).sum())
+        .collect();
+
+    let mut days: Vec<_> = history
+        .iter()
+        .flat_map(|x| x.timings.data.iter().map(|t| t.day))
+        .collect();
+    days.sort_unstable();
+    days.dedup();
+
+    let values = history
+        .iter()
+        .flat_map(|x| x.timings.data.iter().map(Timing::total_nanos))
+        .chain(totals.iter().copied());
+
+    let Some(scale) = LogScale::new(values, HISTORY_HEIGHT - AXIS_HEIGHT) else {
+        return "<p>No timings stored yet.</p>\n".into();
+    };
+
+    let plot_width = CHART_WIDTH - LABEL_WIDTH - VALUE_WIDTH;
+    #[allow(clippy::cast_precision_loss)]
+    let step = plot_width / (history.len() - 1) as f64;
+    #[allow(clippy::cast_precision_loss)]
+    let x = |i: usize| LABEL_WIDTH + i as f64 * step;
+    let y = |nanos: f64| HISTORY_HEIGHT - AXIS_HEIGHT - scale.position(nanos);
+
+    let mut svg = open_svg(HISTORY_HEIGHT);
+    svg.push_str(&scale.render_grid(LABEL_WIDTH, 0.0, plot_width, false));
+
+    for day in days {
+        let points: Vec<String> = history
+            .iter()
+            .enumerate()
+            .filter_map(|(i, snapshot)| {
+                let timing = snapshot.timings.data.iter().find(|t| t.day == day)?;
+                let nanos = timing.total_nanos();
+                (nanos > 0.0).then(|| format!("{:.1},{:.1}", x(i), y(nanos)))
+            })
+            .collect();
+
+        let _ = writeln!(
+            svg,
+            "<polyline points=\"{}\" fill=\"none\" stroke=\"{COLOR_GRID}\" stroke-width=\"1.5\"><title>Day {day}</title></polyline>",
+            points.join(" ")
+        );
+    }
+
+    let points: Vec<String> = totals
+        .iter()
+        .enumerate()
+        .filter(|(_, nanos)| **nanos > 0.0)
+        .map(|(i, nanos)| format!("{:.1},{:.1}", x(i), y(*nanos)))
+        .collect();
+
+    let _ = writeln!(
+        svg,
+        "<polyline points=\"{}\" fill=\"none\" stroke=\"{COLOR_TOTAL}\" stroke-width=\"2.5\"><title>Total</title></polyline>",
+        points.join(" ")
+    );
+
+    let label_step = history.len().div_ceil(MAX_HISTORY_LABELS);
+
+    for (i, snapshot) in history.iter().enumerate().step_by(label_step) {
+        let label = snapshot.date.as_deref().unwrap_or("working tree");
+        let title = snapshot.commit.as_deref().unwrap_or("uncommitted");
+
+        let _ = writeln!(
+            svg,
+            "<text class=\"muted\" x=\"{:.1}\" y=\"{:.1}\" text-anchor=\"middle\"><title>{}</title>{}</text>",
+            x(i),
+            HISTORY_HEIGHT - 4.0,
+            escape(title),
+            escape(label)
+        );
+    }
+
+    svg.push_str("</svg>\n");
+
+    let mut html = render_legend(&[("Total", COLOR_TOTAL), ("Days", COLOR_GRID)]);
+    html.push_str(&svg);
+    html
+}
+
+fn render_table(timings: &Timings) -> String {
+    let mut html = String::from(
+        "<table>\n<tr><th>Day</th><th>Part 1</th><th>Part 2</th><th>Total</th></tr>\n",
+    );
+
+    for timing in &timings.data {
+        let _ = writeln!(
+            html,
+            "<tr><td>Day {}</td><td>{}</td><td>{}</td><td>{}</td></tr>",
+            timing.day,
+            escape(&timing.format_part(1)),
+            escape(&timing.format_part(2)),
+            format_nanos(timing.total_nanos())
+        );
+    }
+
+    html.push_str("</table>\n");
+    html
+}
+
+fn render_legend(entries: &[(&str, &str)]) -> String {
+    let entries: String = entries
+        .iter()
+        .map(|(label, color)| format!("<span><i style=\"background: {color}\"></i>{label}</span>"))
+        .collect();
+
+    format!("<p class=\"legend\">{entries}</p>\n")
+}
+
+fn open_svg(height: f64) -> String {
+    format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{CHART_WIDTH}\" height=\"{height:.1}\" viewBox=\"0 0 {CHART_WIDTH} {height:.1}\">\n"
+    )
+}
+
+fn escape(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Maps nanoseconds to a position along an axis of `length`, with one segment per power of ten.
+struct LogScale {
+    min_exponent: i32,
+    max_exponent: i32,
+    length: f64,
+}
+
+impl LogScale {
+    /// Returns `None` if there are no positive values.
+    fn new(values: impl Iterator<Item = f64>, length: f64) -> Option<Self> {
+        let (min, max) = values
+            .filter(|x| *x > 0.0)
+            .fold(None, |acc: Option<(f64, f64)>, x| match acc {
+                Some((min, max)) => Some((min.min(x), max.max(x))),
+                None => Some((x, x)),
+            })?;
+
+        #[allow(clippy::cast_possible_truncation)]
+        let min_exponent = min.max(1.0).log10().floor() as i32;
+        #[allow(clippy::cast_possible_truncation)]
+        let max_exponent = (max.max(1.0).log10().ceil() as i32).max(min_exponent + 1);
+
+        Some(LogScale {
+            min_exponent,
+            max_exponent,
+            length,
+        })
+    }
+
+    fn position(&self, nanos: f64) -> f64 {
+        let exponent = nanos.max(1.0).log10() - f64::from(self.min_exponent);
+        exponent / f64::from(self.max_exponent - self.min_exponent) * self.length
+    }
+
+    /// Grid lines and labels for each power of ten.
+    /// With `horizontal`, the axis runs left to right from `offset` and grid lines span `extent` downwards.
+    /// Otherwise the axis runs bottom to top and grid lines span `extent` to the right of `offset`.
+    fn render_grid(&self, offset: f64, start: f64, extent: f64, horizontal: bool) -> String {
+        let mut svg = String::new();
+
+        for exponent in self.min_exponent..=self.max_exponent {
+            let nanos = 10_f64.powi(exponent);
+            let label = format_nanos(nanos).replace(".0", "");
+            let position = self.position(nanos);
+
+            if horizontal {
+                let x = offset + position;
+                let _ = writeln!(
+                    svg,
+                    "<line x1=\"{x:.1}\" y1=\"{start:.1}\" x2=\"{x:.1}\" y2=\"{extent:.1}\" stroke=\"{COLOR_GRID}\"/>"
+                );
+                let _ = writeln!(
+                    svg,
+                    "<text class=\"muted\" x=\"{x:.1}\" y=\"{:.1}\" text-anchor=\"middle\">{label}</text>",
+                    extent + 16.0
+                );
+            } else {
+                let y = self.length - position;
+                let _ = writeln!(
+                    svg,
+                    "<line x1=\"{offset:.1}\" y1=\"{y:.1}\" x2=\"{:.1}\" y2=\"{y:.1}\" stroke=\"{COLOR_GRID}\" stroke-dasharray=\"2 3\"/>",
+                    offset + extent
+                );
+                let _ = writeln!(
+                    svg,
+                    "<text class=\"muted\" x=\"{:.1}\" y=\"{:.1}\" text-anchor=\"end\">{label}</text>",
+                    offset - 8.0,
+                    y + 4.0
+                );
+            }
+        }
+
+        svg
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{render, LogScale};
+    use crate::day;
+    use crate::template::timings::{PartTiming, Timing, Timings};
+    use crate::template::timings_history::Snapshot;
+
+    fn get_mock_timings(factor: f64) -> Timings {
+        Timings {
+            data: vec![
+                Timing {
+                    part_1: Some(PartTiming::solved(10_000.0 * factor)),
+                    part_2: Some(PartTiming::solved(2_000_000.0 * factor)),
+                    ..Timing::new(day!(1))
+                },
+                Timing {
+                    part_1: Some(PartTiming::solved(1_500_000_000.0 * factor)),
+                    ..Timing::new(day!(2))
+                },
+            ],
+        }
+    }
+
+    #[test]
+    fn maps_to_log_scale() {
+        let scale = LogScale::new([10.0, 2_000.0].into_iter(), 300.0).unwrap();
+        assert_eq!(scale.min_exponent, 1);
+        assert_eq!(scale.max_exponent, 4);
+        assert!((scale.position(10.0) - 0.0).abs() < 1e-9);
+        assert!((scale.position(100.0) - 100.0).abs() < 1e-9);
+        assert!((scale.position(10_000.0) - 300.0).abs() < 1e-9);
+        assert!(LogScale::new([0.0].into_iter(), 300.0).is_none());
+    }
+
+    #[test]
+    fn renders_self_contained_page() {
+        let html = render(&get_mock_timings(1.0), &[]);
+
+        assert!(html.starts_with("<!DOCTYPE html>"));
+        assert!(!html.contains("<script"));
+        assert!(!html.contains("src=") && !html.contains("href="));
+        assert!(html.contains("<title>Day 01: 2.0ms</title>"));
+        assert!(html.contains("<title>Day 01 part 2: 2.0ms</title>"));
+        assert!(html.contains("<title>Day 02 part 1: 1.5s</title>"));
+        assert!(!html.contains("Day 02 part 2"));
+        assert!(html.contains("has been committed"));
+    }
+
+    #[test]
+    fn renders_history() {
+        let history = vec![
+            Snapshot {
+                commit: Some("abc".into()),
+                date: Some("2024-12-01".into()),
+                timings: get_mock_timings(2.0),
+            },
+            Snapshot {
+                commit: None,
+                date: None,
+                timings: get_mock_timings(1.0),
+            },
+        ];
+
+        let html = render(&get_mock_timings(1.0), &history);
+        assert!(html.contains("<title>Total</title>"));
+        assert!(html.contains("<title>Day 02</title>"));
+        assert!(html.contains(">2024-12-01</text>"));
+        assert!(html.contains(">working tree</text>"));
+    }
+}
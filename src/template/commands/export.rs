@@ -0,0 +1,71 @@
+use std::{fs, process};
+
+use crate::template::timings::TimingProfiles;
+use crate::template::timings_export::render;
+pub use crate::template::timings_export::Format;
+use crate::template::timings_history::{self, Snapshot};
+
+/// Exports the timings of `profile` in the working tree, or all committed snapshots of them with `history`.
+/// Writes to stdout if no output path is given.
+pub fn handle(
+    format: Option<Format>,
+    output: Option<String>,
+    history: bool,
+    profile: Option<&str>,
+) {
+    let format = format
+        .or_else(|| output.as_deref().and_then(Format::from_path))
+        .unwrap_or(Format::Csv);
+
+    let snapshots = if history {
+        timings_history::load(profile)
+    } else {
+        current_snapshot(profile)
+    };
+
+    if snapshots.is_empty() {
+        match profile {
+            Some(profile) => eprintln!("No timings stored for profile \"{profile}\"."),
+            None => eprintln!("No timings stored yet. Run `cargo time --store` first."),
+        }
+        process::exit(1);
+    }
+
+    let content = render(
+        &snapshots,
+        format,
+        timings_history::head_commit().as_deref(),
+    );
+
+    match output {
+        Some(path) => match fs::write(&path, content) {
+            Ok(()) => println!("Exported timings to \"{path}\"."),
+            Err(e) => {
+                eprintln!("Failed to export timings: {e}");
+                process::exit(1);
+            }
+        },
+        None => print!("{content}"),
+    }
+}
+
+/// The stored timings of `profile`, without looking at the git history.
+fn current_snapshot(profile: Option<&str>) -> Vec<Snapshot> {
+    let profiles = match TimingProfiles::try_read_from_file() {
+        Ok(profiles) => profiles,
+        Err(e) => {
+            eprintln!("Failed to read timings: {e}");
+            process::exit(1);
+        }
+    };
+
+    profiles
+        .select(profile)
+        .map(|x| Snapshot {
+            commit: None,
+            date: None,
+            timings: x.timings.clone(),
+        })
+        .into_iter()
+        .collect()
+}
@@ -0,0 +1,98 @@
+use std::{fs, process};
+
+use chacha20poly1305::Key;
+
+use crate::template::encrypted_inputs::{self, get_encrypted_path, get_plain_path, Error};
+use crate::template::{all_days, Day};
+
+/// Encrypts the inputs of `day`, or of every day if `None`, into `data/inputs.enc/`.
+pub fn encrypt(day: Option<Day>) {
+    let key = match encrypted_inputs::load_key() {
+        Ok(key) => key,
+        Err(Error::KeyNotFound) => generate_key(),
+        Err(e) => exit_with(&e),
+    };
+
+    if let Err(e) = fs::create_dir_all("data/inputs.enc") {
+        exit_with(&Error::IO(e));
+    }
+
+    for day in days(day) {
+        let Some(plaintext) = fs::read(get_plain_path(day)).ok().filter(|x| !x.is_empty()) else {
+            continue;
+        };
+
+        let encrypted_path = get_encrypted_path(day);
+
+        // re-encrypting uses a new nonce, skip unchanged inputs to keep the git history clean.
+        let is_unchanged = fs::read(&encrypted_path)
+            .ok()
+            .and_then(|blob| encrypted_inputs::decrypt(&key, &blob).ok())
+            .is_some_and(|existing| existing == plaintext);
+
+        if is_unchanged {
+            println!("Unchanged \"{}\"", encrypted_path.display());
+            continue;
+        }
+
+        match fs::write(&encrypted_path, encrypted_inputs::encrypt(&key, &plaintext)) {
+            Ok(()) => println!("Encrypted input to \"{}\"", encrypted_path.display()),
+            Err(e) => exit_with(&Error::IO(e)),
+        }
+    }
+}
+
+/// Decrypts the inputs of `day`, or of every day if `None`, into `data/inputs/`.
+/// Existing plaintext inputs are kept unless `overwrite` is set.
+pub fn decrypt(day: Option<Day>, overwrite: bool) {
+    let key = encrypted_inputs::load_key().unwrap_or_else(|e| exit_with(&e));
+
+    for day in days(day) {
+        let Ok(blob) = fs::read(get_encrypted_path(day)) else {
+            continue;
+        };
+
+        let plain_path = get_plain_path(day);
+        let has_plaintext = fs::metadata(&plain_path).is_ok_and(|m| m.len() > 0);
+
+        if has_plaintext && !overwrite {
+            println!(
+                "Skipped existing input \"{}\", use `--overwrite` to replace it.",
+                plain_path.display()
+            );
+            continue;
+        }
+
+        let plaintext = encrypted_inputs::decrypt(&key, &blob).unwrap_or_else(|e| {
+            eprintln!("Failed to decrypt input for day {day}.");
+            exit_with(&e)
+        });
+
+        match fs::write(&plain_path, plaintext) {
+            Ok(()) => println!("Decrypted input to \"{}\"", plain_path.display()),
+            Err(e) => exit_with(&Error::IO(e)),
+        }
+    }
+}
+
+fn days(day: Option<Day>) -> Vec<Day> {
+    day.map_or_else(|| all_days().collect(), |day| vec![day])
+}
+
+fn generate_key() -> Key {
+    match encrypted_inputs::generate_key_file() {
+        Ok(key) => {
+            println!(
+                "🔑 Generated a new key in \"{}\". Keep it out of git and share it with your team, e.g. as the `AOC_INPUTS_KEY` secret in CI.",
+                encrypted_inputs::get_key_file_path()
+            );
+            key
+        }
+        Err(e) => exit_with(&e),
+    }
+}
+
+fn exit_with(e: &Error) -> ! {
+    eprintln!("Error: {e}");
+    process::exit(1);
+}
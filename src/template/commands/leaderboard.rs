@@ -0,0 +1,84 @@
+use std::{fs, path::Path, process, time::Duration};
+
+use crate::template::leaderboard::{render_day, render_summary, Leaderboard};
+use crate::template::{aoc_api, aoc_cli, Day, ANSI_BOLD, ANSI_RESET};
+
+/// The advent of code website asks to not request private leaderboards more often than every 15 minutes.
+const CACHE_TTL: Duration = Duration::from_secs(15 * 60);
+
+pub fn handle(id: u32, day: Option<Day>, refresh: bool) {
+    let json = match load(id, refresh) {
+        Ok(json) => json,
+        Err(e) => {
+            eprintln!("Failed to fetch leaderboard: {e}");
+            process::exit(1);
+        }
+    };
+
+    let leaderboard = match Leaderboard::try_from(json) {
+        Ok(leaderboard) => leaderboard,
+        Err(e) => {
+            eprintln!("Failed to parse leaderboard: {e}");
+            process::exit(1);
+        }
+    };
+
+    println!(
+        "{ANSI_BOLD}Private leaderboard {id} ({}){ANSI_RESET}",
+        leaderboard.event
+    );
+    println!();
+    println!("{}", render_summary(&leaderboard));
+
+    if let Some(day) = day.or_else(|| leaderboard.latest_day()) {
+        println!();
+        println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
+        println!();
+        println!("{}", render_day(&leaderboard, day));
+    }
+}
+
+pub fn get_cache_path(id: u32) -> String {
+    format!("data/leaderboards/{id}.json")
+}
+
+/// Returns the cached leaderboard if it is recent enough, fetches and caches it otherwise.
+/// Falls back to a stale cache if fetching fails.
+pub fn load(id: u32, refresh: bool) -> Result<String, String> {
+    let cache_path = get_cache_path(id);
+    let cached = fs::read_to_string(&cache_path).ok();
+
+    if !refresh && cached.is_some() && is_fresh(&cache_path) {
+        return cached.ok_or_else(|| "cache disappeared.".into());
+    }
+
+    let year = aoc_cli::get_year().ok_or("`AOC_YEAR` is not set.")?;
+
+    let fetched =
+        aoc_api::AocClient::from_env().and_then(|client| client.private_leaderboard(year, id));
+
+    match fetched {
+        Ok(json) => {
+            if let Some(dir) = Path::new(&cache_path).parent() {
+                fs::create_dir_all(dir).map_err(|e| e.to_string())?;
+            }
+            fs::write(&cache_path, &json).map_err(|e| e.to_string())?;
+            Ok(json)
+        }
+        Err(e) => match cached {
+            Some(json) => {
+                eprintln!("Failed to refresh leaderboard, showing cached version: {e}");
+                Ok(json)
+            }
+            None => Err(e.to_string()),
+        },
+    }
+}
+
+fn is_fresh(path: &str) -> bool {
+    fs::metadata(path)
+        .and_then(|m| m.modified())
+        .ok()
+        .and_then(|modified| modified.elapsed().ok())
+        .is_some_and(|age| age < CACHE_TTL)
+}
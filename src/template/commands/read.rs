@@ -1,8 +1,119 @@
-use std::process;
+use std::io::{stdout, IsTerminal, Write};
+use std::path::{Path, PathBuf};
+use std::process::{self, Command, Stdio};
+use std::{env, fs};
 
+use crate::template::aoc_api::AocClient;
+use crate::template::puzzle_diff::new_content;
+use crate::template::puzzle_html::to_markdown;
+use crate::template::puzzle_markdown::{render, select_part};
 use crate::template::{aoc_cli, Day};
 
-pub fn handle(day: Day) {
+/// Terminal height that is assumed if `$LINES` is not set.
+const DEFAULT_TERMINAL_LINES: usize = 24;
+
+/// Renders the stored puzzle description if present, fetches it via aoc-cli otherwise.
+pub fn handle(day: Day, part: Option<u8>, new: bool) {
+    if new {
+        if let Err(e) = show_new(day) {
+            eprintln!("Failed to refresh puzzle: {e}");
+            process::exit(1);
+        }
+        return;
+    }
+
+    let puzzle_path = format!("data/puzzles/{day}.md");
+
+    match fs::read_to_string(&puzzle_path) {
+        Ok(markdown) if !markdown.trim().is_empty() => {
+            eprintln!("Showing the stored \"{puzzle_path}\", run `cargo read {day} --new` to download it again.");
+
+            let Some(section) = select_part(&markdown, part) else {
+                eprintln!(
+                    "\"{puzzle_path}\" does not contain part two yet. Solve part one and run `cargo download {day}` to refresh it."
+                );
+                process::exit(1);
+            };
+
+            page(&render(section));
+        }
+        _ => {
+            if part.is_some() {
+                eprintln!("\"{puzzle_path}\" not found, `--part` is ignored.");
+            }
+            read_online(day);
+        }
+    }
+}
+
+/// Parses the value of `--part`, which is either `1` or `2`.
+pub fn parse_part(s: &str) -> Result<u8, String> {
+    match s.parse() {
+        Ok(part @ 1..=2) => Ok(part),
+        _ => Err(format!("1 or 2, got \"{s}\".")),
+    }
+}
+
+/// Fetches the puzzle again and prints the text that was added since the previous download.
+pub fn show_new(day: Day) -> Result<(), String> {
+    let year = aoc_cli::get_year().ok_or("`AOC_YEAR` is not set.")?;
+    let client = AocClient::from_env().map_err(|e| e.to_string())?;
+    let (previous, current) = refresh(&client, year, day, Path::new("data/puzzles"))?;
+
+    let Some(previous) = previous else {
+        println!("No earlier download of day {day} to compare against, stored the current puzzle.");
+        return Ok(());
+    };
+
+    match new_content(&previous, &current) {
+        Some(added) => page(&render(&added)),
+        None => println!("The puzzle text of day {day} did not change."),
+    }
+
+    Ok(())
+}
+
+/// Fetches the puzzle and stores it as `dir/NN.fetched.md`, so that the `dir/NN.md` written by aoc-cli is left alone.
+/// If the text changed, the earlier fetch is kept as `dir/NN.previous.md`. Before the first fetch, the aoc-cli download is the earlier version.
+/// Returns the earlier version, if any, and the current puzzle.
+fn refresh(
+    client: &AocClient,
+    year: u16,
+    day: Day,
+    dir: &Path,
+) -> Result<(Option<String>, String), String> {
+    let html = client.puzzle(year, day).map_err(|e| e.to_string())?;
+    let current = to_markdown(&html);
+
+    let fetched_path = dir.join(format!("{day}.fetched.md"));
+    let previous_path = get_previous_path(dir, day);
+    let stored = fs::read_to_string(&fetched_path)
+        .or_else(|_| fs::read_to_string(dir.join(format!("{day}.md"))))
+        .ok();
+
+    fs::create_dir_all(dir).map_err(|e| e.to_string())?;
+    fs::write(&fetched_path, &current).map_err(|e| e.to_string())?;
+
+    let changed = stored
+        .as_deref()
+        .is_none_or(|stored| new_content(stored, &current).is_some());
+
+    if !changed {
+        return Ok((fs::read_to_string(&previous_path).ok(), current));
+    }
+
+    if let Some(stored) = &stored {
+        fs::write(&previous_path, stored).map_err(|e| e.to_string())?;
+    }
+
+    Ok((stored, current))
+}
+
+fn get_previous_path(dir: &Path, day: Day) -> PathBuf {
+    dir.join(format!("{day}.previous.md"))
+}
+
+fn read_online(day: Day) {
     if aoc_cli::check().is_err() {
         eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
         process::exit(1);
@@ -13,3 +124,136 @@ pub fn handle(day: Day) {
         process::exit(1);
     };
 }
+
+/// Prints `content`, piping it through `$PAGER` (default: `less -R`) if it does not fit the terminal.
+fn page(content: &str) {
+    let terminal_lines = env::var("LINES")
+        .ok()
+        .and_then(|x| x.parse().ok())
+        .unwrap_or(DEFAULT_TERMINAL_LINES);
+
+    if !stdout().is_terminal() || content.lines().count() < terminal_lines {
+        println!("{content}");
+        return;
+    }
+
+    let pager = env::var("PAGER").unwrap_or_else(|_| "less -R".into());
+    let mut pager_args = pager.split_whitespace();
+
+    let child = pager_args.next().and_then(|cmd| {
+        Command::new(cmd)
+            .args(pager_args)
+            .stdin(Stdio::piped())
+            .spawn()
+            .ok()
+    });
+
+    let Some(mut child) = child else {
+        println!("{content}");
+        return;
+    };
+
+    if let Some(mut stdin) = child.stdin.take() {
+        // the pager closing early (e.g. quitting `less`) is not an error.
+        let _ = writeln!(stdin, "{content}");
+    }
+
+    let _ = child.wait();
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::io::{BufRead, BufReader, Write};
+    use std::net::TcpListener;
+    use std::{env, fs, thread};
+
+    use super::{get_previous_path, parse_part, refresh};
+    use crate::day;
+    use crate::template::aoc_api::AocClient;
+
+    static DOWNLOADED: &str = include_str!(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/data/fixtures/puzzle-part-1.md"
+    ));
+
+    static PART_ONE: &str = include_str!(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/data/fixtures/puzzle-part-1.html"
+    ));
+
+    static PART_TWO: &str = include_str!(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/data/fixtures/puzzle-part-2.html"
+    ));
+
+    /// Serves `pages` in order, one per request, and returns the requested paths.
+    fn serve(pages: Vec<&'static str>) -> (String, thread::JoinHandle<Vec<String>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+
+        let server = thread::spawn(move || {
+            let mut paths = vec![];
+
+            for page in pages {
+                let (mut stream, _) = listener.accept().unwrap();
+                let mut request = String::new();
+                BufReader::new(&stream).read_line(&mut request).unwrap();
+                paths.push(request.split_whitespace().nth(1).unwrap().to_string());
+
+                write!(
+                    stream,
+                    "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{page}",
+                    page.len()
+                )
+                .unwrap();
+            }
+
+            paths
+        });
+
+        (url, server)
+    }
+
+    #[test]
+    fn parses_parts() {
+        assert_eq!(parse_part("2"), Ok(2));
+        assert!(parse_part("0").is_err());
+        assert!(parse_part("3").is_err());
+        assert!(parse_part("two").is_err());
+    }
+
+    #[test]
+    fn refreshes_against_fixture_server() {
+        let dir = env::temp_dir().join(format!("aoc-read-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("01.md"), DOWNLOADED).unwrap();
+
+        let (url, server) = serve(vec![PART_ONE, PART_TWO, PART_TWO]);
+        let client = AocClient::new(&url, "session");
+
+        // the first fetch matches the download of aoc-cli, so there is nothing to compare against yet.
+        let (previous, current) = refresh(&client, 2024, day!(1), &dir).unwrap();
+        assert_eq!(previous, None);
+        assert!(!current.contains("Part Two"));
+
+        let (previous, current) = refresh(&client, 2024, day!(1), &dir).unwrap();
+        assert!(!previous.as_ref().unwrap().contains("Part Two"));
+        assert!(current.contains("Part Two"));
+        assert_eq!(
+            fs::read_to_string(dir.join("01.fetched.md")).unwrap(),
+            current
+        );
+
+        // refreshing again keeps comparing against the fetch before part two.
+        let (again, _) = refresh(&client, 2024, day!(1), &dir).unwrap();
+        assert_eq!(again, previous);
+        assert!(get_previous_path(&dir, day!(1)).exists());
+        assert_eq!(fs::read_to_string(dir.join("01.md")).unwrap(), DOWNLOADED);
+
+        assert_eq!(server.join().unwrap(), vec!["/2024/day/1"; 3]);
+        assert!(refresh(&client, 2024, day!(1), &dir).is_err());
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
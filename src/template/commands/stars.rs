@@ -0,0 +1,50 @@
+use std::process;
+
+use crate::template::answers::Answers;
+use crate::template::commands::leaderboard;
+use crate::template::leaderboard::Leaderboard;
+use crate::template::{aoc_cli, readme_stars};
+
+pub fn handle(store: bool, leaderboard_id: Option<u32>, user_id: Option<u64>) {
+    let Some(year) = aoc_cli::get_year() else {
+        eprintln!("`AOC_YEAR` is not set.");
+        process::exit(1);
+    };
+
+    let answers = Answers::read_from_file();
+
+    let leaderboard = leaderboard_id.and_then(|id| {
+        match leaderboard::load(id, false).and_then(Leaderboard::try_from) {
+            Ok(leaderboard) => Some(leaderboard),
+            Err(e) => {
+                eprintln!("Failed to load leaderboard, using local progress only: {e}");
+                None
+            }
+        }
+    });
+
+    let member = leaderboard.as_ref().and_then(|leaderboard| {
+        let id = user_id.unwrap_or(leaderboard.owner_id);
+        let member = leaderboard.get_member(id);
+        if member.is_none() {
+            eprintln!("User {id} is not a member of the leaderboard, using local progress only.");
+        }
+        member
+    });
+
+    let stars = readme_stars::collect_stars(&answers, member);
+
+    if !store {
+        println!("{}", readme_stars::render(year, &stars));
+        return;
+    }
+
+    match readme_stars::update(year, &stars) {
+        Ok(()) => {
+            println!("Stored updated stars.");
+        }
+        Err(_) => {
+            eprintln!("Failed to store updated stars.");
+        }
+    }
+}
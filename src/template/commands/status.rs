@@ -0,0 +1,242 @@
+use std::{
+    fs,
+    path::Path,
+    process::{Command, Stdio},
+};
+
+use crate::template::answers::Answers;
+use crate::template::readme_benchmarks::get_path_for_bin;
+use crate::template::table::render_table;
+use crate::template::timings::{Timing, TimingProfiles};
+use crate::template::{all_days, Day};
+
+/// State of a data file that is expected to be filled in by the user.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FileState {
+    Missing,
+    Empty,
+    Present,
+}
+
+impl FileState {
+    pub fn of(path: &Path) -> Self {
+        match fs::metadata(path) {
+            Ok(metadata) if metadata.len() == 0 => Self::Empty,
+            Ok(_) => Self::Present,
+            Err(_) => Self::Missing,
+        }
+    }
+}
+
+/// Everything that is known locally about a single day.
+#[derive(Clone, Debug)]
+pub struct DayStatus {
+    pub day: Day,
+    pub has_solution: bool,
+    pub input: FileState,
+    pub examples: usize,
+    pub empty_examples: usize,
+    /// `None` if the tests were not run.
+    pub tests_pass: Option<bool>,
+    pub stars: [bool; 2],
+    pub known_answers: usize,
+    pub timing: Option<Timing>,
+}
+
+pub fn handle(run_tests: bool) {
+    let answers = Answers::read_from_file();
+    let timings = TimingProfiles::read_from_file()
+        .select(None)
+        .map(|x| x.timings.clone())
+        .unwrap_or_default();
+
+    let statuses: Vec<DayStatus> = all_days()
+        .map(|day| {
+            let has_solution = Path::new(&get_path_for_bin(day)).exists();
+            let (examples, empty_examples) = count_examples(Path::new("data/examples"), day);
+
+            DayStatus {
+                day,
+                has_solution,
+                input: FileState::of(&Path::new("data/inputs").join(format!("{day}.txt"))),
+                examples,
+                empty_examples,
+                tests_pass: (run_tests && has_solution).then(|| run_example_tests(day)),
+                stars: [answers.is_solved(day, 1), answers.is_solved(day, 2)],
+                known_answers: [1, 2]
+                    .iter()
+                    .filter(|part| answers.get(day, **part).is_some_and(|p| p.answer.is_some()))
+                    .count(),
+                timing: timings.data.iter().find(|t| t.day == day).cloned(),
+            }
+        })
+        .collect();
+
+    println!("{}", render_status(&statuses));
+}
+
+/// Counts the example files of a day (`NN.txt` and `NN-<part>.txt`) and how many of them are empty.
+pub fn count_examples(dir: &Path, day: Day) -> (usize, usize) {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return (0, 0);
+    };
+
+    let exact = format!("{day}.txt");
+    let prefix = format!("{day}-");
+
+    entries
+        .filter_map(Result::ok)
+        .filter(|entry| {
+            let name = entry.file_name().to_string_lossy().to_string();
+            name == exact || (name.starts_with(&prefix) && name.ends_with(".txt"))
+        })
+        .fold((0, 0), |(count, empty), entry| {
+            let is_empty = FileState::of(&entry.path()) != FileState::Present;
+            (count + 1, empty + usize::from(is_empty))
+        })
+}
+
+fn run_example_tests(day: Day) -> bool {
+    Command::new("cargo")
+        .args(["test", "--quiet", "--bin", &day.to_string()])
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .status()
+        .is_ok_and(|status| status.success())
+}
+
+pub fn render_status(statuses: &[DayStatus]) -> String {
+    let check = |x: bool| if x { "✓" } else { "·" }.to_string();
+
+    let rows = statuses
+        .iter()
+        .map(|status| {
+            let input = match status.input {
+                FileState::Missing => "·".into(),
+                FileState::Empty => "empty".into(),
+                FileState::Present => "✓".into(),
+            };
+
+            let examples = match (status.examples, status.empty_examples) {
+                (0, _) => "·".into(),
+                (n, 0) => n.to_string(),
+                (n, empty) => format!("{n} ({empty} empty)"),
+            };
+
+            let tests = match status.tests_pass {
+                Some(true) => "✓",
+                Some(false) => "✖",
+                None => "-",
+            };
+
+            let stars: String = status
+                .stars
+                .iter()
+                .map(|x| if *x { '★' } else { '·' })
+                .collect();
+
+            let (part_1, part_2) = status.timing.as_ref().map_or_else(
+                || ("-".into(), "-".into()),
+                |t| (t.format_part(1), t.format_part(2)),
+            );
+
+            vec![
+                status.day.to_string(),
+                check(status.has_solution),
+                input,
+                examples,
+                tests.into(),
+                stars,
+                format!("{}/2", status.known_answers),
+                part_1,
+                part_2,
+            ]
+        })
+        .collect();
+
+    render_table(
+        &[
+            "Day".into(),
+            "Solution".into(),
+            "Input".into(),
+            "Examples".into(),
+            "Tests".into(),
+            "Stars".into(),
+            "Answers".into(),
+            "Part 1".into(),
+            "Part 2".into(),
+        ],
+        rows,
+    )
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::{env, fs};
+
+    use super::{count_examples, render_status, DayStatus, FileState};
+    use crate::day;
+    use crate::template::timings::{PartTiming, Timing};
+
+    #[test]
+    fn counts_examples() {
+        let dir = env::temp_dir().join(format!("aoc-status-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("03.txt"), "").unwrap();
+        fs::write(dir.join("03-1.txt"), "abc").unwrap();
+        fs::write(dir.join("03-2.txt"), "abc").unwrap();
+        fs::write(dir.join("13.txt"), "abc").unwrap();
+
+        assert_eq!(count_examples(&dir, day!(3)), (3, 1));
+        assert_eq!(count_examples(&dir, day!(13)), (1, 0));
+        assert_eq!(count_examples(&dir, day!(4)), (0, 0));
+        assert_eq!(FileState::of(&dir.join("03.txt")), FileState::Empty);
+        assert_eq!(FileState::of(&dir.join("04.txt")), FileState::Missing);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn renders_status() {
+        let statuses = vec![
+            DayStatus {
+                day: day!(1),
+                has_solution: true,
+                input: FileState::Present,
+                examples: 1,
+                empty_examples: 0,
+                tests_pass: Some(true),
+                stars: [true, true],
+                known_answers: 2,
+                timing: Some(Timing {
+                    part_1: Some(PartTiming::solved(1e+7)),
+                    part_2: Some(PartTiming::solved(2e+7)),
+                    ..Timing::new(day!(1))
+                }),
+            },
+            DayStatus {
+                day: day!(2),
+                has_solution: true,
+                input: FileState::Empty,
+                examples: 2,
+                empty_examples: 1,
+                tests_pass: Some(false),
+                stars: [true, false],
+                known_answers: 0,
+                timing: None,
+            },
+        ];
+
+        let expected = [
+            "Day │ Solution │ Input │ Examples    │ Tests │ Stars │ Answers │ Part 1 │ Part 2",
+            "────┼──────────┼───────┼─────────────┼───────┼───────┼─────────┼────────┼───────",
+            "01  │ ✓        │ ✓     │ 1           │ ✓     │ ★★    │ 2/2     │ 10.0ms │ 20.0ms",
+            "02  │ ✓        │ empty │ 2 (1 empty) │ ✖     │ ★·    │ 0/2     │ -      │ -",
+        ]
+        .join("\n");
+
+        assert_eq!(render_status(&statuses), expected);
+    }
+}
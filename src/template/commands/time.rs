@@ -1,36 +1,105 @@
 use std::collections::HashSet;
+use std::path::Path;
+use std::{fs, process};
 
+use crate::template::host::{local_profile_name, Host};
 use crate::template::run_multi::run_multi;
-use crate::template::timings::Timings;
-use crate::template::{all_days, readme_benchmarks, Day};
+use crate::template::runner::BenchOverrides;
+use crate::template::timings::{get_file_path, Profile, TimingProfiles, Timings};
+use crate::template::{
+    all_days, benchmark_report, readme_benchmarks, timings_compare, timings_history, Day,
+};
 
-pub fn handle(day: Option<Day>, run_all: bool, store: bool) {
-    let stored_timings = Timings::read_from_file();
+/// Benches solutions and stores the timings to the profile of this machine.
+/// `profile` selects the stored profile that the readme, report and comparison are based on.
+/// `bench` overrides the bench settings of the solutions.
+/// With `dry_run`, the changes to the benchmark tables are printed and nothing is written.
+#[allow(clippy::too_many_arguments, clippy::fn_params_excessive_bools)]
+pub fn handle(
+    day: Option<Day>,
+    run_all: bool,
+    store: bool,
+    dry_run: bool,
+    report: Option<String>,
+    profile: Option<String>,
+    compare: bool,
+    bench: &BenchOverrides,
+) {
+    // refuse to work with timings that cannot be read, so that `--store` does not overwrite them.
+    let mut stored_profiles = TimingProfiles::try_read_from_file().unwrap_or_else(|e| {
+        eprintln!("Failed to read \"{}\": {e}", get_file_path());
+        process::exit(1);
+    });
+
+    if let Some(name) = &profile {
+        if stored_profiles.get(name).is_none()
+            && !((store || dry_run) && *name == local_profile_name())
+        {
+            let names: Vec<&str> = stored_profiles
+                .profiles
+                .iter()
+                .map(|x| x.name.as_str())
+                .collect();
+            eprintln!(
+                "No timings stored for profile \"{name}\". Stored profiles: {}.",
+                if names.is_empty() {
+                    "none".into()
+                } else {
+                    names.join(", ")
+                }
+            );
+            process::exit(1);
+        }
+    }
+
+    if compare {
+        print_comparison(&stored_profiles, profile.as_deref());
+        return;
+    }
+
+    if let Some(dir) = report {
+        let timings = selected_timings(&stored_profiles, profile.as_deref());
+        write_report(&timings, profile.as_deref(), Path::new(&dir));
+        return;
+    }
+
+    let local_profile = local_profile_name();
+    let local_timings = stored_profiles
+        .get(&local_profile)
+        .map(|x| x.timings.clone())
+        .unwrap_or_default();
 
     let days_to_run = day.map_or_else(
         || {
             if run_all {
                 all_days().collect()
             } else {
-                // when the `--all` flag is not set, filter out days that are fully benched.
+                // when the `--all` flag is not set, filter out days that are fully benched on this machine.
                 all_days()
-                    .filter(|day| !stored_timings.is_day_complete(*day))
+                    .filter(|day| !local_timings.is_day_complete(*day))
                     .collect()
             }
         },
         |day| HashSet::from([day]),
     );
 
-    let timings = run_multi(&days_to_run, true, true).unwrap();
+    let timings = run_multi(&days_to_run, true, true, &bench.to_args()).unwrap();
 
-    if store {
-        let merged_timings = stored_timings.merge(&timings);
-        merged_timings.store_file().unwrap();
+    if store || dry_run {
+        stored_profiles.store(&local_profile, Host::detect(), &timings);
+
+        if !dry_run {
+            stored_profiles.store_file().unwrap();
+        }
 
         println!();
-        match readme_benchmarks::update(merged_timings) {
+        let readme_timings = selected_timings(&stored_profiles, profile.as_deref());
+        match readme_benchmarks::update(&readme_timings, dry_run) {
+            Ok(()) if dry_run => {
+                println!("Dry run, no timings or benchmark tables were written.");
+            }
             Ok(()) => {
-                println!("Stored updated benchmarks.");
+                println!("Stored updated benchmarks to profile \"{local_profile}\".");
             }
             Err(_) => {
                 eprintln!("Failed to store updated benchmarks.");
@@ -38,3 +107,41 @@ pub fn handle(day: Option<Day>, run_all: bool, store: bool) {
         }
     }
 }
+
+fn selected_timings(profiles: &TimingProfiles, name: Option<&str>) -> Timings {
+    profiles
+        .select(name)
+        .map(|x| x.timings.clone())
+        .unwrap_or_default()
+}
+
+/// Prints all stored profiles, starting with the selected one.
+fn print_comparison(profiles: &TimingProfiles, name: Option<&str>) {
+    let selected = profiles.select(name);
+
+    let ordered: Vec<&Profile> = selected
+        .into_iter()
+        .chain(
+            profiles
+                .profiles
+                .iter()
+                .filter(|x| Some(x.name.as_str()) != selected.map(|x| x.name.as_str())),
+        )
+        .collect();
+
+    println!("{}", timings_compare::render(&ordered));
+}
+
+/// Renders the stored timings and their history to `dir/index.html`.
+fn write_report(timings: &Timings, profile: Option<&str>, dir: &Path) {
+    let html = benchmark_report::render(timings, &timings_history::load(profile));
+    let path = dir.join("index.html");
+
+    match fs::create_dir_all(dir).and_then(|()| fs::write(&path, html)) {
+        Ok(()) => println!("Wrote benchmark report to \"{}\".", path.display()),
+        Err(e) => {
+            eprintln!("Failed to write benchmark report: {e}");
+            process::exit(1);
+        }
+    }
+}
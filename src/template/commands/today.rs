@@ -0,0 +1,245 @@
+use std::io::{stdout, Write};
+use std::{process, thread, time::Duration};
+
+use chrono::{DateTime, Utc};
+
+use crate::template::commands::{download, read, scaffold};
+use crate::template::{aoc_cli, Day, ANSI_BOLD, ANSI_RESET};
+
+/// How often the download is retried when the server is slow to publish a freshly unlocked puzzle.
+const DOWNLOAD_ATTEMPTS: u32 = 5;
+const DOWNLOAD_RETRY_DELAY: Duration = Duration::from_secs(3);
+
+/// Source of the current time. Abstracted so that waiting for an unlock can be tested without sleeping.
+pub trait Clock {
+    fn now(&self) -> DateTime<Utc>;
+    fn sleep(&self, duration: Duration);
+}
+
+/// [`Clock`] backed by the system time.
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> DateTime<Utc> {
+        Utc::now()
+    }
+
+    fn sleep(&self, duration: Duration) {
+        thread::sleep(duration);
+    }
+}
+
+pub fn handle(wait: bool) {
+    let day = if wait {
+        let mut counted_down = false;
+        let day = wait_for_unlock(&SystemClock, |day, remaining| {
+            counted_down = true;
+            print_countdown(day, remaining);
+        });
+
+        // the countdown rewrites its line, so it is ended once the wait is over.
+        if counted_down {
+            println!();
+        }
+
+        day
+    } else {
+        Day::today()
+    };
+
+    let Some(day) = day else {
+        if wait {
+            eprintln!("There is no puzzle left to unlock this year.");
+        } else {
+            eprintln!(
+                "`today` command can only be run between the 1st and \
+                the 25th of december. Please use `scaffold` with a specific day."
+            );
+        }
+        process::exit(1);
+    };
+
+    scaffold::handle(day, false);
+
+    if wait {
+        download_with_retry(&SystemClock, day);
+    } else {
+        download::handle(day);
+    }
+
+    read::handle(day, None, false);
+}
+
+/// Blocks until the next puzzle unlocks, calling `on_tick` with the remaining time roughly once a second.
+/// Returns the unlocked day, or `None` if there is no puzzle left to unlock.
+pub fn wait_for_unlock(clock: &impl Clock, mut on_tick: impl FnMut(Day, Duration)) -> Option<Day> {
+    let (day, unlock) = Day::next_unlock(clock.now())?;
+
+    loop {
+        let remaining = (unlock - clock.now()).to_std().unwrap_or_default();
+
+        if remaining.is_zero() {
+            return Some(day);
+        }
+
+        on_tick(day, remaining);
+        clock.sleep(remaining.min(Duration::from_secs(1)));
+    }
+}
+
+/// Calls `func` until it succeeds, sleeping `delay` between at most `attempts` tries.
+pub fn retry<T, E>(
+    clock: &impl Clock,
+    attempts: u32,
+    delay: Duration,
+    mut func: impl FnMut() -> Result<T, E>,
+) -> Result<T, E> {
+    let mut attempt = 1;
+
+    loop {
+        match func() {
+            Ok(x) => return Ok(x),
+            Err(e) if attempt >= attempts => return Err(e),
+            Err(_) => {
+                attempt += 1;
+                clock.sleep(delay);
+            }
+        }
+    }
+}
+
+fn download_with_retry(clock: &impl Clock, day: Day) {
+    if aoc_cli::check().is_err() {
+        eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
+        process::exit(1);
+    }
+
+    if let Err(e) = retry(clock, DOWNLOAD_ATTEMPTS, DOWNLOAD_RETRY_DELAY, || {
+        aoc_cli::download(day)
+    }) {
+        eprintln!("failed to call aoc-cli: {e}");
+        process::exit(1);
+    }
+}
+
+fn print_countdown(day: Day, remaining: Duration) {
+    let secs = remaining.as_secs();
+    print!(
+        "\r⏳ Day {day} unlocks in {ANSI_BOLD}{:02}:{:02}:{:02}{ANSI_RESET} ",
+        secs / 3600,
+        (secs / 60) % 60,
+        secs % 60
+    );
+
+    let _ = stdout().flush();
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::cell::{Cell, RefCell};
+    use std::time::Duration;
+
+    use chrono::{DateTime, TimeZone, Utc};
+
+    use super::{retry, wait_for_unlock, Clock};
+    use crate::day;
+
+    /// Clock that only advances when slept on.
+    struct MockClock {
+        now: Cell<DateTime<Utc>>,
+        sleeps: RefCell<Vec<Duration>>,
+    }
+
+    impl MockClock {
+        fn new(now: DateTime<Utc>) -> Self {
+            Self {
+                now: Cell::new(now),
+                sleeps: RefCell::new(vec![]),
+            }
+        }
+    }
+
+    impl Clock for MockClock {
+        fn now(&self) -> DateTime<Utc> {
+            self.now.get()
+        }
+
+        fn sleep(&self, duration: Duration) {
+            self.sleeps.borrow_mut().push(duration);
+            self.now
+                .set(self.now.get() + chrono::Duration::from_std(duration).unwrap());
+        }
+    }
+
+    #[test]
+    fn waits_until_unlock() {
+        let clock = MockClock::new(Utc.with_ymd_and_hms(2024, 12, 6, 4, 59, 57).unwrap());
+        let mut ticks = vec![];
+
+        let day = wait_for_unlock(&clock, |day, remaining| ticks.push((day, remaining)));
+
+        assert_eq!(day, Some(day!(6)));
+        assert_eq!(ticks.len(), 3);
+        assert_eq!(ticks[0], (day!(6), Duration::from_secs(3)));
+        assert_eq!(
+            clock.now(),
+            Utc.with_ymd_and_hms(2024, 12, 6, 5, 0, 0).unwrap()
+        );
+    }
+
+    #[test]
+    fn handles_sub_second_remainders() {
+        let start = Utc.with_ymd_and_hms(2024, 12, 6, 4, 59, 58).unwrap()
+            + chrono::Duration::milliseconds(500);
+        let clock = MockClock::new(start);
+
+        let day = wait_for_unlock(&clock, |_, _| {});
+
+        assert_eq!(day, Some(day!(6)));
+        assert_eq!(
+            *clock.sleeps.borrow(),
+            vec![Duration::from_secs(1), Duration::from_millis(500)]
+        );
+    }
+
+    #[test]
+    fn does_not_wait_after_last_unlock() {
+        let clock = MockClock::new(Utc.with_ymd_and_hms(2024, 12, 26, 0, 0, 0).unwrap());
+        assert_eq!(wait_for_unlock(&clock, |_, _| {}), None);
+        assert!(clock.sleeps.borrow().is_empty());
+    }
+
+    #[test]
+    fn retries_until_success() {
+        let clock = MockClock::new(Utc.with_ymd_and_hms(2024, 12, 6, 5, 0, 0).unwrap());
+        let mut calls = 0;
+
+        let result: Result<u32, &str> = retry(&clock, 5, Duration::from_secs(2), || {
+            calls += 1;
+            if calls < 3 {
+                Err("not yet")
+            } else {
+                Ok(calls)
+            }
+        });
+
+        assert_eq!(result, Ok(3));
+        assert_eq!(clock.sleeps.borrow().len(), 2);
+    }
+
+    #[test]
+    fn gives_up_after_max_attempts() {
+        let clock = MockClock::new(Utc.with_ymd_and_hms(2024, 12, 6, 5, 0, 0).unwrap());
+        let mut calls = 0;
+
+        let result: Result<(), &str> = retry(&clock, 3, Duration::from_secs(2), || {
+            calls += 1;
+            Err("still down")
+        });
+
+        assert_eq!(result, Err("still down"));
+        assert_eq!(calls, 3);
+    }
+}
@@ -3,7 +3,7 @@ use std::fmt::Display;
 use std::str::FromStr;
 
 #[cfg(feature = "today")]
-use chrono::{Datelike, FixedOffset, Utc};
+use chrono::{DateTime, Datelike, FixedOffset, NaiveDate, TimeZone, Utc};
 
 #[cfg(feature = "today")]
 const SERVER_UTC_OFFSET: i32 = -5;
@@ -47,14 +47,43 @@ impl Day {
 impl Day {
     /// Returns the current day if it's between the 1st and the 25th of december, `None` otherwise.
     pub fn today() -> Option<Self> {
+        Self::today_at(Utc::now())
+    }
+
+    /// Returns the day that is unlocked at `now` if it's between the 1st and the 25th of december, `None` otherwise.
+    pub fn today_at(now: DateTime<Utc>) -> Option<Self> {
         let offset = FixedOffset::east_opt(SERVER_UTC_OFFSET * 3600)?;
-        let today = Utc::now().with_timezone(&offset);
+        let today = now.with_timezone(&offset);
         if today.month() == 12 && today.day() <= 25 {
             Self::new(u8::try_from(today.day()).ok()?)
         } else {
             None
         }
     }
+
+    /// Returns the next day to unlock after `now` together with its unlock time (midnight at the server's offset).
+    /// Returns `None` once the last puzzle of the year has been unlocked.
+    pub fn next_unlock(now: DateTime<Utc>) -> Option<(Self, DateTime<Utc>)> {
+        let offset = FixedOffset::east_opt(SERVER_UTC_OFFSET * 3600)?;
+        let today = now.with_timezone(&offset);
+
+        let date = if today.month() < 12 {
+            NaiveDate::from_ymd_opt(today.year(), 12, 1)?
+        } else {
+            today.date_naive().succ_opt()?
+        };
+
+        if date.month() != 12 || date.day() > 25 {
+            return None;
+        }
+
+        let unlock = offset
+            .from_local_datetime(&date.and_hms_opt(0, 0, 0)?)
+            .single()?
+            .with_timezone(&Utc);
+
+        Some((Self::new(u8::try_from(date.day()).ok()?)?, unlock))
+    }
 }
 
 impl Display for Day {
@@ -156,6 +185,37 @@ macro_rules! day {
 mod tests {
     use super::{all_days, Day};
 
+    #[cfg(feature = "today")]
+    mod next_unlock {
+        use chrono::{TimeZone, Utc};
+
+        use crate::template::Day;
+
+        #[test]
+        fn returns_first_day_before_december() {
+            let now = Utc.with_ymd_and_hms(2024, 11, 30, 12, 0, 0).unwrap();
+            let (day, unlock) = Day::next_unlock(now).unwrap();
+            assert_eq!(day, day!(1));
+            assert_eq!(unlock, Utc.with_ymd_and_hms(2024, 12, 1, 5, 0, 0).unwrap());
+        }
+
+        #[test]
+        fn returns_next_day_at_server_midnight() {
+            // 03:00 UTC on the 6th is still the 5th on the server.
+            let now = Utc.with_ymd_and_hms(2024, 12, 6, 3, 0, 0).unwrap();
+            let (day, unlock) = Day::next_unlock(now).unwrap();
+            assert_eq!(day, day!(6));
+            assert_eq!(unlock, Utc.with_ymd_and_hms(2024, 12, 6, 5, 0, 0).unwrap());
+            assert_eq!(Day::today_at(now), Some(day!(5)));
+        }
+
+        #[test]
+        fn returns_none_after_last_unlock() {
+            let now = Utc.with_ymd_and_hms(2024, 12, 25, 6, 0, 0).unwrap();
+            assert_eq!(Day::next_unlock(now), None);
+        }
+    }
+
     #[test]
     fn all_days_iterator() {
         let mut iter = all_days();
@@ -0,0 +1,197 @@
+/// Stores puzzle inputs as encrypted blobs, so that they can be committed without publishing them.
+/// Blobs are encrypted with ChaCha20-Poly1305 and laid out as `MAGIC | nonce | ciphertext`.
+use std::io::{self, Write};
+use std::{env, fmt::Display, fs, path::PathBuf};
+
+use chacha20poly1305::aead::{Aead, AeadCore, KeyInit, OsRng};
+use chacha20poly1305::{ChaCha20Poly1305, Key, Nonce};
+
+use crate::template::Day;
+
+static MAGIC: &[u8] = b"AOCENC1";
+static KEY_ENV_VAR: &str = "AOC_INPUTS_KEY";
+static KEY_FILE_PATH: &str = ".aoc-inputs.key";
+const NONCE_LEN: usize = 12;
+
+#[derive(Debug)]
+pub enum Error {
+    KeyNotFound,
+    InvalidKey,
+    InvalidBlob,
+    Decryption,
+    IO(io::Error),
+}
+
+impl From<io::Error> for Error {
+    fn from(e: io::Error) -> Self {
+        Error::IO(e)
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::KeyNotFound => {
+                write!(f, "no key found in ${KEY_ENV_VAR} or \"{KEY_FILE_PATH}\".")
+            }
+            Error::InvalidKey => write!(f, "the key is not 64 hexadecimal characters."),
+            Error::InvalidBlob => write!(f, "the file is not an encrypted input."),
+            Error::Decryption => write!(f, "decryption failed, the key is probably wrong."),
+            Error::IO(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+pub fn get_plain_path(day: Day) -> PathBuf {
+    PathBuf::from("data")
+        .join("inputs")
+        .join(format!("{day}.txt"))
+}
+
+pub fn get_encrypted_path(day: Day) -> PathBuf {
+    PathBuf::from("data")
+        .join("inputs.enc")
+        .join(format!("{day}.txt.enc"))
+}
+
+/// Loads the key from `AOC_INPUTS_KEY`, or from the local key file if the variable is not set.
+pub fn load_key() -> Result<Key, Error> {
+    let hex = match env::var(KEY_ENV_VAR) {
+        Ok(key) => key,
+        Err(_) => fs::read_to_string(KEY_FILE_PATH).map_err(|_| Error::KeyNotFound)?,
+    };
+    parse_key(&hex)
+}
+
+/// Generates a random key and writes it to the local key file, which only the owner can read.
+pub fn generate_key_file() -> Result<Key, Error> {
+    let key = ChaCha20Poly1305::generate_key(&mut OsRng);
+
+    let mut options = fs::OpenOptions::new();
+    options.write(true).create(true).truncate(true);
+    #[cfg(unix)]
+    std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+
+    let mut file = options.open(KEY_FILE_PATH)?;
+    // the mode only applies to new files, an existing key file is restricted as well.
+    #[cfg(unix)]
+    file.set_permissions(std::os::unix::fs::PermissionsExt::from_mode(0o600))?;
+
+    writeln!(file, "{}", to_hex(&key))?;
+    Ok(key)
+}
+
+pub fn get_key_file_path() -> &'static str {
+    KEY_FILE_PATH
+}
+
+pub fn encrypt(key: &Key, plaintext: &[u8]) -> Vec<u8> {
+    let cipher = ChaCha20Poly1305::new(key);
+    let nonce = ChaCha20Poly1305::generate_nonce(&mut OsRng);
+    let ciphertext = cipher
+        .encrypt(&nonce, plaintext)
+        .expect("encrypting an in-memory buffer can not fail");
+
+    [MAGIC, nonce.as_slice(), &ciphertext].concat()
+}
+
+pub fn decrypt(key: &Key, blob: &[u8]) -> Result<Vec<u8>, Error> {
+    let rest = blob.strip_prefix(MAGIC).ok_or(Error::InvalidBlob)?;
+
+    if rest.len() < NONCE_LEN {
+        return Err(Error::InvalidBlob);
+    }
+
+    let (nonce, ciphertext) = rest.split_at(NONCE_LEN);
+    ChaCha20Poly1305::new(key)
+        .decrypt(Nonce::from_slice(nonce), ciphertext)
+        .map_err(|_| Error::Decryption)
+}
+
+/// Reads and decrypts the stored input of a day.
+pub fn read_input(day: Day) -> Result<String, Error> {
+    let key = load_key()?;
+    let blob = fs::read(get_encrypted_path(day))?;
+    let plaintext = decrypt(&key, &blob)?;
+    String::from_utf8(plaintext).map_err(|_| Error::InvalidBlob)
+}
+
+fn parse_key(hex: &str) -> Result<Key, Error> {
+    let hex = hex.trim();
+
+    // `from_str_radix` accepts a leading sign, so every character is checked up front.
+    if hex.len() != 64 || !hex.bytes().all(|b| b.is_ascii_hexdigit()) {
+        return Err(Error::InvalidKey);
+    }
+
+    let bytes = (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&hex[i..i + 2], 16))
+        .collect::<Result<Vec<u8>, _>>()
+        .map_err(|_| Error::InvalidKey)?;
+
+    Ok(*Key::from_slice(&bytes))
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{b:02x}")).collect()
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{decrypt, encrypt, parse_key, to_hex, Error};
+
+    static KEY: &str = "000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f";
+
+    #[test]
+    fn roundtrips_inputs() {
+        let key = parse_key(KEY).unwrap();
+        let blob = encrypt(&key, b"1 2 3\n4 5 6\n");
+        assert_ne!(&blob[..], b"1 2 3\n4 5 6\n");
+        assert_eq!(decrypt(&key, &blob).unwrap(), b"1 2 3\n4 5 6\n");
+    }
+
+    #[test]
+    fn uses_fresh_nonces() {
+        let key = parse_key(KEY).unwrap();
+        assert_ne!(encrypt(&key, b"input"), encrypt(&key, b"input"));
+    }
+
+    #[test]
+    fn rejects_wrong_keys() {
+        let key = parse_key(KEY).unwrap();
+        let other = parse_key(&KEY.replace("00", "ff")).unwrap();
+        let blob = encrypt(&key, b"input");
+        assert!(matches!(decrypt(&other, &blob), Err(Error::Decryption)));
+    }
+
+    #[test]
+    fn rejects_tampered_blobs() {
+        let key = parse_key(KEY).unwrap();
+        let mut blob = encrypt(&key, b"input");
+        let last = blob.len() - 1;
+        blob[last] ^= 1;
+        assert!(matches!(decrypt(&key, &blob), Err(Error::Decryption)));
+        assert!(matches!(
+            decrypt(&key, b"plain text"),
+            Err(Error::InvalidBlob)
+        ));
+    }
+
+    #[test]
+    fn parses_keys() {
+        let key = parse_key(&format!("  {KEY}\n")).unwrap();
+        assert_eq!(to_hex(&key), KEY);
+        assert!(matches!(parse_key("abc"), Err(Error::InvalidKey)));
+        assert!(matches!(
+            parse_key(&KEY.replace('0', "g")),
+            Err(Error::InvalidKey)
+        ));
+        assert!(matches!(
+            parse_key(&KEY.replacen("00", "+0", 1)),
+            Err(Error::InvalidKey)
+        ));
+    }
+}
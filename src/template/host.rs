@@ -0,0 +1,164 @@
+/// Detects the machine that benchmarks run on, so that timings of different machines can be stored side by side.
+/// The name of the local profile is taken from `AOC_PROFILE` and defaults to the hostname.
+use std::{collections::HashMap, env, fs, process::Command, thread};
+
+use tinyjson::JsonValue;
+
+static PROFILE_ENV_VAR: &str = "AOC_PROFILE";
+
+/// Name of the local profile on machines without a hostname.
+static DEFAULT_PROFILE: &str = "default";
+
+/// Describes the hardware and toolchain timings were measured with.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Host {
+    pub cpu: String,
+    pub cores: usize,
+    pub os: String,
+    pub rustc: String,
+}
+
+impl Host {
+    pub fn detect() -> Self {
+        Host {
+            cpu: detect_cpu().unwrap_or_else(|| "unknown".into()),
+            cores: thread::available_parallelism().map_or(1, usize::from),
+            os: format!("{} {}", env::consts::OS, env::consts::ARCH),
+            rustc: command_output("rustc", &["--version"]).unwrap_or_else(|| "unknown".into()),
+        }
+    }
+}
+
+/// Name of the profile that timings of this machine are stored to.
+/// Timings stored before profiles existed are migrated to this profile as well.
+pub fn local_profile_name() -> String {
+    env::var(PROFILE_ENV_VAR)
+        .ok()
+        .or_else(hostname)
+        .map(|x| x.trim().to_lowercase())
+        .filter(|x| !x.is_empty())
+        .unwrap_or_else(|| DEFAULT_PROFILE.into())
+}
+
+fn hostname() -> Option<String> {
+    env::var("COMPUTERNAME")
+        .or_else(|_| env::var("HOSTNAME"))
+        .ok()
+        .or_else(|| command_output("hostname", &[]))
+}
+
+fn detect_cpu() -> Option<String> {
+    if cfg!(target_os = "macos") {
+        command_output("sysctl", &["-n", "machdep.cpu.brand_string"])
+    } else if cfg!(windows) {
+        env::var("PROCESSOR_IDENTIFIER").ok()
+    } else {
+        parse_cpuinfo(&fs::read_to_string("/proc/cpuinfo").ok()?)
+    }
+}
+
+/// Reads the model name from `/proc/cpuinfo`. ARM machines name it `Model` or `Hardware` instead.
+fn parse_cpuinfo(cpuinfo: &str) -> Option<String> {
+    ["model name", "Model", "Hardware"].iter().find_map(|key| {
+        cpuinfo.lines().find_map(|line| {
+            let (k, v) = line.split_once(':')?;
+            (k.trim() == *key && !v.trim().is_empty()).then(|| v.trim().to_string())
+        })
+    })
+}
+
+fn command_output(program: &str, args: &[&str]) -> Option<String> {
+    let output = Command::new(program).args(args).output().ok()?;
+
+    output
+        .status
+        .success()
+        .then(|| String::from_utf8_lossy(&output.stdout).trim().to_string())
+        .filter(|x| !x.is_empty())
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&Host> for JsonValue {
+    fn from(value: &Host) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert("cpu".into(), JsonValue::String(value.cpu.clone()));
+        #[allow(clippy::cast_precision_loss)]
+        map.insert("cores".into(), JsonValue::Number(value.cores as f64));
+        map.insert("os".into(), JsonValue::String(value.os.clone()));
+        map.insert("rustc".into(), JsonValue::String(value.rustc.clone()));
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for Host {
+    type Error = String;
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected profile.host to be a JSON object.")?;
+
+        let get_string = |key: &str| {
+            json.get(key)
+                .and_then(|v| v.get::<String>())
+                .cloned()
+                .ok_or(format!("Expected host.{key} to be a string."))
+        };
+
+        let cores = json
+            .get("cores")
+            .and_then(|v| v.get::<f64>())
+            .ok_or("Expected host.cores to be a number.")?;
+
+        Ok(Host {
+            cpu: get_string("cpu")?,
+            cores: *cores as usize,
+            os: get_string("os")?,
+            rustc: get_string("rustc")?,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use tinyjson::JsonValue;
+
+    use super::{parse_cpuinfo, Host};
+
+    #[test]
+    fn parses_cpuinfo() {
+        let x86 =
+            "processor\t: 0\nvendor_id\t: GenuineIntel\nmodel name\t: Intel(R) Core(TM) i7-8550U\n";
+        assert_eq!(
+            parse_cpuinfo(x86),
+            Some("Intel(R) Core(TM) i7-8550U".into())
+        );
+
+        let arm =
+            "processor\t: 0\nBogoMIPS\t: 108.00\n\nModel\t\t: Raspberry Pi 4 Model B Rev 1.4\n";
+        assert_eq!(
+            parse_cpuinfo(arm),
+            Some("Raspberry Pi 4 Model B Rev 1.4".into())
+        );
+
+        assert_eq!(parse_cpuinfo("processor\t: 0\n"), None);
+    }
+
+    #[test]
+    fn roundtrips_hosts() {
+        let host = Host {
+            cpu: "Apple M1".into(),
+            cores: 8,
+            os: "macos aarch64".into(),
+            rustc: "rustc 1.83.0".into(),
+        };
+
+        assert_eq!(Host::try_from(&JsonValue::from(&host)), Ok(host));
+    }
+}
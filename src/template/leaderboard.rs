@@ -0,0 +1,392 @@
+/// Parses and renders the JSON document of a private leaderboard.
+use std::{collections::BTreeMap, collections::HashMap, str::FromStr};
+use tinyjson::JsonValue;
+
+use crate::template::table::render_table;
+use crate::template::Day;
+
+/// Puzzles unlock at midnight UTC-5.
+const UNLOCK_HOUR_UTC: i64 = 5;
+
+/// Represents a private leaderboard.
+#[derive(Clone, Debug)]
+pub struct Leaderboard {
+    pub event: u16,
+    pub owner_id: u64,
+    pub members: Vec<Member>,
+}
+
+/// Represents a single member of a private leaderboard and their progress.
+#[derive(Clone, Debug)]
+pub struct Member {
+    pub id: u64,
+    pub name: Option<String>,
+    pub stars: u32,
+    pub local_score: u32,
+    pub days: BTreeMap<Day, DayProgress>,
+}
+
+/// Completion timestamps (seconds since the unix epoch) of the two parts of a day.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DayProgress {
+    pub part_1: i64,
+    pub part_2: Option<i64>,
+}
+
+impl Leaderboard {
+    /// Unix timestamp at which the puzzle for `day` unlocked.
+    pub fn unlock_timestamp(&self, day: Day) -> i64 {
+        let days = days_from_civil(i64::from(self.event), 12, i64::from(day.into_inner()));
+        days * 86_400 + UNLOCK_HOUR_UTC * 3600
+    }
+
+    /// Members ordered by local score, best first.
+    pub fn ranked_members(&self) -> Vec<&Member> {
+        let mut members: Vec<&Member> = self.members.iter().collect();
+        members.sort_by(|a, b| {
+            b.local_score
+                .cmp(&a.local_score)
+                .then(b.stars.cmp(&a.stars))
+                .then(a.id.cmp(&b.id))
+        });
+        members
+    }
+
+    pub fn get_member(&self, id: u64) -> Option<&Member> {
+        self.members.iter().find(|m| m.id == id)
+    }
+
+    /// The most recent day any member has collected a star for.
+    pub fn latest_day(&self) -> Option<Day> {
+        self.members
+            .iter()
+            .filter_map(|m| m.days.keys().next_back())
+            .max()
+            .copied()
+    }
+}
+
+impl Member {
+    pub fn display_name(&self) -> String {
+        self.name
+            .clone()
+            .unwrap_or_else(|| format!("(anonymous user #{})", self.id))
+    }
+}
+
+impl DayProgress {
+    /// Seconds it took to get from the first to the second star.
+    pub fn delta(&self) -> Option<i64> {
+        self.part_2.map(|part_2| part_2 - self.part_1)
+    }
+}
+
+/// Days since 1970-01-01 for a date in the proleptic gregorian calendar.
+/// See: <http://howardhinnant.github.io/date_algorithms.html#days_from_civil>
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let yoe = year - era * 400;
+    let doy = (153 * (month + if month > 2 { -3 } else { 9 }) + 2) / 5 + day - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146_097 + doe - 719_468
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Renders an overview of all members, one row per member and one star glyph per day.
+pub fn render_summary(leaderboard: &Leaderboard) -> String {
+    let last_day = leaderboard.latest_day().map_or(0, Day::into_inner);
+
+    let rows = leaderboard
+        .ranked_members()
+        .iter()
+        .enumerate()
+        .map(|(i, member)| {
+            let days: String = (1..=last_day)
+                .map(|day| star_glyph(Day::new(day).and_then(|day| member.days.get(&day))))
+                .collect();
+
+            vec![
+                format!("{}", i + 1),
+                member.display_name(),
+                member.local_score.to_string(),
+                member.stars.to_string(),
+                days,
+            ]
+        })
+        .collect();
+
+    let day_header: String = (1..=last_day)
+        .map(|day| char::from(b'0' + day % 10))
+        .collect();
+
+    render_table(
+        &[
+            "#".into(),
+            "Name".into(),
+            "Score".into(),
+            "Stars".into(),
+            day_header,
+        ],
+        rows,
+    )
+}
+
+/// Renders completion times of a single day, relative to the puzzle unlock.
+pub fn render_day(leaderboard: &Leaderboard, day: Day) -> String {
+    let unlock = leaderboard.unlock_timestamp(day);
+
+    let mut entries: Vec<(&Member, &DayProgress)> = leaderboard
+        .members
+        .iter()
+        .filter_map(|member| member.days.get(&day).map(|progress| (member, progress)))
+        .collect();
+
+    entries.sort_by_key(|(member, progress)| {
+        (
+            progress.part_2.unwrap_or(i64::MAX),
+            progress.part_1,
+            member.id,
+        )
+    });
+
+    let rows = entries
+        .iter()
+        .map(|(member, progress)| {
+            vec![
+                member.display_name(),
+                format_elapsed(progress.part_1 - unlock),
+                progress
+                    .part_2
+                    .map_or_else(|| "-".into(), |ts| format_elapsed(ts - unlock)),
+                progress.delta().map_or_else(|| "-".into(), format_elapsed),
+            ]
+        })
+        .collect();
+
+    render_table(
+        &[
+            "Name".into(),
+            "Part 1".into(),
+            "Part 2".into(),
+            "Part 1 → 2".into(),
+        ],
+        rows,
+    )
+}
+
+fn star_glyph(progress: Option<&DayProgress>) -> char {
+    match progress {
+        Some(DayProgress {
+            part_2: Some(_), ..
+        }) => '★',
+        Some(_) => '☆',
+        None => '·',
+    }
+}
+
+/// Formats a duration in seconds as `HH:MM:SS`, prefixed with the number of days if longer than one.
+pub fn format_elapsed(secs: i64) -> String {
+    let secs = secs.max(0);
+    let (days, secs) = (secs / 86_400, secs % 86_400);
+    let time = format!(
+        "{:02}:{:02}:{:02}",
+        secs / 3600,
+        (secs / 60) % 60,
+        secs % 60
+    );
+
+    if days > 0 {
+        format!("{days}d {time}")
+    } else {
+        time
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl TryFrom<String> for Leaderboard {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let json = JsonValue::from_str(&value).or(Err("not valid JSON file."))?;
+
+        let json = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("expected JSON document to be an object.")?;
+
+        let event = json
+            .get("event")
+            .and_then(|v| v.get::<String>())
+            .and_then(|v| v.parse().ok())
+            .ok_or("expected `json.event` to be a year.")?;
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let owner_id = json
+            .get("owner_id")
+            .and_then(|v| v.get::<f64>())
+            .map(|v| *v as u64)
+            .ok_or("expected `json.owner_id` to be a number.")?;
+
+        let members = json
+            .get("members")
+            .and_then(|v| v.get::<HashMap<String, JsonValue>>())
+            .ok_or("expected `json.members` to be an object.")?;
+
+        Ok(Leaderboard {
+            event,
+            owner_id,
+            members: members
+                .values()
+                .map(Member::try_from)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+impl TryFrom<&JsonValue> for Member {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected member to be a JSON object.")?;
+
+        let number = |key: &str| {
+            json.get(key)
+                .and_then(|v| v.get::<f64>().copied())
+                .ok_or(format!("Expected member.{key} to be a number."))
+        };
+
+        let name = json
+            .get("name")
+            .map(|v| if v.is_null() { None } else { v.get::<String>() })
+            .ok_or("Expected member.name to be null or string.")?;
+
+        let completion = json
+            .get("completion_day_level")
+            .and_then(|v| v.get::<HashMap<String, JsonValue>>())
+            .ok_or("Expected member.completion_day_level to be an object.")?;
+
+        let days = completion
+            .iter()
+            .map(|(day, parts)| {
+                let day = Day::from_str(day).map_err(|e| e.to_string())?;
+                let parts = parts
+                    .get::<HashMap<String, JsonValue>>()
+                    .ok_or("Expected completion_day_level entries to be objects.")?;
+
+                let get_star_ts = |part: &str| {
+                    parts
+                        .get(part)
+                        .and_then(|v| v.get::<HashMap<String, JsonValue>>())
+                        .and_then(|v| v.get("get_star_ts"))
+                        .and_then(|v| v.get::<f64>().copied())
+                        .map(|ts| ts as i64)
+                };
+
+                let part_1 = get_star_ts("1").ok_or("Expected part 1 to have a get_star_ts.")?;
+
+                Ok((
+                    day,
+                    DayProgress {
+                        part_1,
+                        part_2: get_star_ts("2"),
+                    },
+                ))
+            })
+            .collect::<Result<_, String>>()?;
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        Ok(Member {
+            id: number("id")? as u64,
+            name: name.cloned(),
+            stars: number("stars")? as u32,
+            local_score: number("local_score")? as u32,
+            days,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{format_elapsed, render_day, render_summary, Leaderboard};
+    use crate::day;
+
+    static FIXTURE: &str = include_str!(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/data/fixtures/leaderboard.json"
+    ));
+
+    fn get_fixture() -> Leaderboard {
+        Leaderboard::try_from(FIXTURE.to_string()).unwrap()
+    }
+
+    #[test]
+    fn parses_leaderboard() {
+        let leaderboard = get_fixture();
+        assert_eq!(leaderboard.event, 2024);
+        assert_eq!(leaderboard.owner_id, 1);
+        assert_eq!(leaderboard.members.len(), 3);
+
+        let ranked = leaderboard.ranked_members();
+        assert_eq!(ranked[0].display_name(), "Alice");
+        assert_eq!(ranked[1].display_name(), "Bob");
+        assert_eq!(ranked[2].display_name(), "(anonymous user #3)");
+
+        let progress = ranked[1].days.get(&day!(2)).unwrap();
+        assert_eq!(progress.part_2, None);
+        assert_eq!(leaderboard.latest_day(), Some(day!(2)));
+    }
+
+    #[test]
+    #[should_panic]
+    fn panics_for_malformed_leaderboard() {
+        Leaderboard::try_from(r#"{ "event": "2024", "members": { "1": {} } }"#.to_string())
+            .unwrap();
+    }
+
+    #[test]
+    fn computes_unlock_timestamp() {
+        let leaderboard = get_fixture();
+        // 2024-12-01T05:00:00Z
+        assert_eq!(leaderboard.unlock_timestamp(day!(1)), 1_733_029_200);
+        assert_eq!(leaderboard.unlock_timestamp(day!(2)), 1_733_115_600);
+    }
+
+    #[test]
+    fn formats_elapsed_time() {
+        assert_eq!(format_elapsed(59), "00:00:59");
+        assert_eq!(format_elapsed(3723), "01:02:03");
+        assert_eq!(format_elapsed(90_061), "1d 01:01:01");
+    }
+
+    #[test]
+    fn renders_summary() {
+        let expected = [
+            "# │ Name                │ Score │ Stars │ 12",
+            "──┼─────────────────────┼───────┼───────┼───",
+            "1 │ Alice               │ 12    │ 4     │ ★★",
+            "2 │ Bob                 │ 7     │ 3     │ ★☆",
+            "3 │ (anonymous user #3) │ 2     │ 1     │ ☆·",
+        ]
+        .join("\n");
+        assert_eq!(render_summary(&get_fixture()), expected);
+    }
+
+    #[test]
+    fn renders_day() {
+        let expected = [
+            "Name                │ Part 1   │ Part 2      │ Part 1 → 2",
+            "────────────────────┼──────────┼─────────────┼───────────",
+            "Alice               │ 00:05:00 │ 00:12:30    │ 00:07:30",
+            "Bob                 │ 00:20:00 │ 1d 00:00:00 │ 23:40:00",
+            "(anonymous user #3) │ 02:00:00 │ -           │ -",
+        ]
+        .join("\n");
+        assert_eq!(render_day(&get_fixture(), day!(1)), expected);
+    }
+}
@@ -0,0 +1,105 @@
+/// Renders the difference between two versions of a file as a unified diff with a single hunk.
+/// The hunk spans everything between the common leading and trailing lines, which suits edits to one region of a file.
+use std::fmt::Write;
+
+const CONTEXT_LINES: usize = 2;
+
+/// Returns `None` if both versions have the same lines.
+pub fn render(path: &str, old: &str, new: &str) -> Option<String> {
+    let old_lines: Vec<&str> = old.lines().collect();
+    let new_lines: Vec<&str> = new.lines().collect();
+
+    if old_lines == new_lines {
+        return None;
+    }
+
+    let prefix = old_lines
+        .iter()
+        .zip(&new_lines)
+        .take_while(|(a, b)| a == b)
+        .count();
+
+    let suffix = old_lines[prefix..]
+        .iter()
+        .rev()
+        .zip(new_lines[prefix..].iter().rev())
+        .take_while(|(a, b)| a == b)
+        .count();
+
+    let start = prefix.saturating_sub(CONTEXT_LINES);
+    let old_changed_end = old_lines.len() - suffix;
+    let new_changed_end = new_lines.len() - suffix;
+    let context_after = suffix.min(CONTEXT_LINES);
+
+    let mut diff = format!(
+        "--- a/{path}\n+++ b/{path}\n@@ -{},{} +{},{} @@\n",
+        start + 1,
+        old_changed_end + context_after - start,
+        start + 1,
+        new_changed_end + context_after - start,
+    );
+
+    let lines = old_lines[start..prefix]
+        .iter()
+        .map(|x| (' ', x))
+        .chain(old_lines[prefix..old_changed_end].iter().map(|x| ('-', x)))
+        .chain(new_lines[prefix..new_changed_end].iter().map(|x| ('+', x)))
+        .chain(
+            old_lines[old_changed_end..old_changed_end + context_after]
+                .iter()
+                .map(|x| (' ', x)),
+        );
+
+    for (sign, line) in lines {
+        let _ = writeln!(diff, "{sign}{line}");
+    }
+
+    Some(diff)
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::render;
+
+    #[test]
+    fn renders_changed_lines_with_context() {
+        let old = "a\nb\nc\nd\ne\nf\ng\n";
+        let new = "a\nb\nc\nD\nd2\ne\nf\ng\n";
+
+        let expected = [
+            "--- a/README.md",
+            "+++ b/README.md",
+            "@@ -2,5 +2,6 @@",
+            " b",
+            " c",
+            "-d",
+            "+D",
+            "+d2",
+            " e",
+            " f",
+            "",
+        ]
+        .join("\n");
+
+        assert_eq!(render("README.md", old, new), Some(expected));
+    }
+
+    #[test]
+    fn renders_changes_at_the_edges() {
+        assert_eq!(
+            render("x", "a", "b"),
+            Some("--- a/x\n+++ b/x\n@@ -1,1 +1,1 @@\n-a\n+b\n".into())
+        );
+        assert_eq!(
+            render("x", "a\nb", "a"),
+            Some("--- a/x\n+++ b/x\n@@ -1,2 +1,1 @@\n a\n-b\n".into())
+        );
+    }
+
+    #[test]
+    fn ignores_equal_lines() {
+        assert_eq!(render("x", "a\nb", "a\nb\n"), None);
+    }
+}
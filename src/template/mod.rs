@@ -1,26 +1,54 @@
-use std::{env, fs};
+use std::{env, fs, io};
 
+pub mod aoc_api;
 pub mod aoc_cli;
 pub mod commands;
+pub mod peak_alloc;
 pub mod runner;
 
 pub use day::*;
 
+mod answers;
+mod benchmark_report;
 mod day;
+mod encrypted_inputs;
+mod host;
+mod leaderboard;
+mod line_diff;
+mod puzzle_diff;
+mod puzzle_html;
+mod puzzle_markdown;
 mod readme_benchmarks;
+mod readme_stars;
 mod run_multi;
+mod table;
 mod timings;
+mod timings_export;
+mod timings_compare;
+mod timings_history;
 
 pub const ANSI_ITALIC: &str = "\x1b[3m";
 pub const ANSI_BOLD: &str = "\x1b[1m";
 pub const ANSI_RESET: &str = "\x1b[0m";
 
 /// Helper function that reads a text file to a string.
+/// Inputs that are missing or empty are decrypted from `data/inputs.enc` if possible.
 #[must_use]
 pub fn read_file(folder: &str, day: Day) -> String {
     let cwd = env::current_dir().unwrap();
     let filepath = cwd.join("data").join(folder).join(format!("{day}.txt"));
     let f = fs::read_to_string(filepath);
+
+    if folder == "inputs" && f.as_ref().map_or(true, String::is_empty) {
+        match encrypted_inputs::read_input(day) {
+            Ok(input) => return input,
+            // without a key or an encrypted input, the plain input is all there is.
+            Err(encrypted_inputs::Error::KeyNotFound) => {}
+            Err(encrypted_inputs::Error::IO(e)) if e.kind() == io::ErrorKind::NotFound => {}
+            Err(e) => panic!("could not decrypt input file: {e}"),
+        }
+    }
+
     f.expect("could not open input file")
 }
 
@@ -39,19 +67,29 @@ pub fn read_file_part(folder: &str, day: Day, part: u8) -> String {
 /// Creates the constant `DAY` and sets up the input and runner for each part.
 ///
 /// The optional, second parameter (1 or 2) allows you to only run a single part of the solution.
+/// The optional `parse = <fn>` parameter names the function that parses the input, which is then benched
+/// separately by `cargo time`. Parts still receive the raw input.
+/// The optional `bench = <BenchConfig>` parameter sets how long the parts of this day are benched.
 #[macro_export]
 macro_rules! solution {
-    ($day:expr) => {
-        $crate::solution!(@impl $day, [part_one, 1] [part_two, 2]);
+    ($day:expr $(, parse = $parse:expr)? $(, bench = $bench:expr)?) => {
+        $crate::solution!(@impl $day, [$($parse)?], [$($bench)?], [part_one, 1] [part_two, 2]);
     };
-    ($day:expr, 1) => {
-        $crate::solution!(@impl $day, [part_one, 1]);
+    ($day:expr, 1 $(, parse = $parse:expr)? $(, bench = $bench:expr)?) => {
+        $crate::solution!(@impl $day, [$($parse)?], [$($bench)?], [part_one, 1]);
     };
-    ($day:expr, 2) => {
-        $crate::solution!(@impl $day, [part_two, 2]);
+    ($day:expr, 2 $(, parse = $parse:expr)? $(, bench = $bench:expr)?) => {
+        $crate::solution!(@impl $day, [$($parse)?], [$($bench)?], [part_two, 2]);
     };
 
-    (@impl $day:expr, $( [$func:expr, $part:expr] )*) => {
+    (@config) => {
+        $crate::template::runner::BenchConfig::new()
+    };
+    (@config $bench:expr) => {
+        $bench
+    };
+
+    (@impl $day:expr, [$($parse:expr)?], [$($bench:expr)?], $( [$func:expr, $part:expr] )*) => {
         /// The current day.
         const DAY: $crate::template::Day = $crate::day!($day);
 
@@ -59,6 +97,10 @@ macro_rules! solution {
         #[global_allocator]
         static ALLOC: dhat::Alloc = dhat::Alloc;
 
+        #[cfg(not(feature = "dhat-heap"))]
+        #[global_allocator]
+        static ALLOC: $crate::template::peak_alloc::PeakAlloc = $crate::template::peak_alloc::PeakAlloc;
+
         fn main() {
             use $crate::template::runner::*;
             use tracing::Level;
@@ -71,8 +113,11 @@ macro_rules! solution {
 
             tracing::subscriber::set_global_default(subscriber).expect("setting default subscriber failed");
 
+            let config = $crate::solution!(@config $($bench)?).with_args();
+
             let input = $crate::template::read_file("inputs", DAY);
-            $( run_part($func, &input, DAY, $part); )*
+            $( run_parse($parse, &input, &config); )?
+            $( run_part($func, &input, DAY, $part, &config); )*
         }
     };
 }
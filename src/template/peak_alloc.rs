@@ -0,0 +1,119 @@
+/// Global allocator that tracks the peak heap usage of a measured section.
+/// It is installed by the `solution!` macro, unless the `dhat-heap` feature provides an allocator instead.
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+
+static INSTALLED: AtomicBool = AtomicBool::new(false);
+static TRACKING: AtomicBool = AtomicBool::new(false);
+static CURRENT: AtomicUsize = AtomicUsize::new(0);
+static PEAK: AtomicUsize = AtomicUsize::new(0);
+
+pub struct PeakAlloc;
+
+unsafe impl GlobalAlloc for PeakAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            track_alloc(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            track_alloc(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        track_dealloc(layout.size());
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            track_dealloc(layout.size());
+            track_alloc(new_size);
+        }
+        new_ptr
+    }
+}
+
+fn track_alloc(size: usize) {
+    if TRACKING.load(Ordering::Relaxed) {
+        let current = CURRENT.fetch_add(size, Ordering::Relaxed) + size;
+        PEAK.fetch_max(current, Ordering::Relaxed);
+    }
+}
+
+fn track_dealloc(size: usize) {
+    if TRACKING.load(Ordering::Relaxed) {
+        // memory allocated before tracking started may be freed while tracking.
+        let _ = CURRENT.fetch_update(Ordering::Relaxed, Ordering::Relaxed, |x| {
+            Some(x.saturating_sub(size))
+        });
+    }
+}
+
+/// Runs `func` and returns its result along with the peak heap usage in bytes.
+/// The peak is `None` if `PeakAlloc` is not the global allocator.
+pub fn measure<T>(func: impl FnOnce() -> T) -> (T, Option<u64>) {
+    TRACKING.store(true, Ordering::Relaxed);
+
+    // a probe allocation shows whether this allocator is installed, which keeps the hot path free of writes.
+    if !INSTALLED.load(Ordering::Relaxed) {
+        drop(std::hint::black_box(Box::new(0_u64)));
+        INSTALLED.store(PEAK.load(Ordering::Relaxed) > 0, Ordering::Relaxed);
+    }
+
+    CURRENT.store(0, Ordering::Relaxed);
+    PEAK.store(0, Ordering::Relaxed);
+
+    let result = func();
+
+    TRACKING.store(false, Ordering::Relaxed);
+
+    let peak = INSTALLED
+        .load(Ordering::Relaxed)
+        .then(|| PEAK.load(Ordering::Relaxed) as u64);
+
+    (result, peak)
+}
+
+/// Formats a number of bytes with a binary unit, e.g. `1.5 KiB`.
+pub fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+
+    if bytes < 1024 {
+        return format!("{bytes} B");
+    }
+
+    #[allow(clippy::cast_precision_loss)]
+    let mut value = bytes as f64 / 1024.0;
+    let mut unit = 0;
+
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+
+    format!("{value:.1} {}", UNITS[unit])
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::format_bytes;
+
+    #[test]
+    fn formats_bytes() {
+        assert_eq!(format_bytes(0), "0 B");
+        assert_eq!(format_bytes(1023), "1023 B");
+        assert_eq!(format_bytes(1536), "1.5 KiB");
+        assert_eq!(format_bytes(3 * 1024 * 1024), "3.0 MiB");
+    }
+}
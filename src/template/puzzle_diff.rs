@@ -0,0 +1,176 @@
+struct Section<'a> {
+    key: String,
+    heading: Option<&'a str>,
+    blocks: Vec<&'a str>,
+}
+
+/// Lowercase alphanumeric characters of `s`, used to compare text regardless of its markup.
+fn normalize(s: &str) -> String {
+    s.chars()
+        .filter(|c| c.is_alphanumeric())
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+/// Splits markdown into blocks separated by blank lines. Code blocks are kept whole.
+fn split_blocks(markdown: &str) -> Vec<&str> {
+    let mut blocks = vec![];
+    let mut start: Option<usize> = None;
+    let mut in_code_block = false;
+    let mut offset = 0;
+
+    for line in markdown.split_inclusive('\n') {
+        if line.trim_start().starts_with("```") {
+            in_code_block = !in_code_block;
+        }
+
+        if line.trim().is_empty() && !in_code_block {
+            if let Some(start) = start.take() {
+                blocks.push(markdown[start..offset].trim_end());
+            }
+        } else if start.is_none() {
+            start = Some(offset);
+        }
+
+        offset += line.len();
+    }
+
+    if let Some(start) = start {
+        blocks.push(markdown[start..].trim_end());
+    }
+
+    blocks
+}
+
+/// Returns the heading text of a block, supporting both `## heading` and underlined headings.
+fn get_heading(block: &str) -> Option<&str> {
+    if let Some(heading) = block.strip_prefix('#') {
+        return Some(heading.trim_start_matches('#').trim());
+    }
+
+    let mut lines = block.lines();
+    let first = lines.next()?;
+    let underline = lines.next()?.trim();
+
+    let is_underline = underline.len() >= 3 && underline.chars().all(|c| c == '-' || c == '=');
+
+    (is_underline && lines.next().is_none()).then_some(first.trim())
+}
+
+fn split_sections(markdown: &str) -> Vec<Section<'_>> {
+    let mut sections = vec![Section {
+        key: String::new(),
+        heading: None,
+        blocks: vec![],
+    }];
+
+    for block in split_blocks(markdown) {
+        match get_heading(block) {
+            Some(heading) => sections.push(Section {
+                key: normalize(heading),
+                heading: Some(block),
+                blocks: vec![],
+            }),
+            None => sections
+                .last_mut()
+                .expect("there is at least one section")
+                .blocks
+                .push(block),
+        }
+    }
+
+    sections
+}
+
+/// Returns the markdown of all sections and paragraphs of `current` that are not part of `previous`,
+/// e.g. part two after part one is solved. Headings are included for context. Returns `None` if nothing was added.
+/// Paragraphs are compared ignoring markup, so that the download of aoc-cli can be compared with a page converted by `puzzle_html`.
+pub fn new_content(previous: &str, current: &str) -> Option<String> {
+    let previous = split_sections(previous);
+    let mut output: Vec<&str> = vec![];
+
+    for section in split_sections(current) {
+        let known = previous.iter().find(|x| x.key == section.key);
+
+        let added: Vec<&str> = section
+            .blocks
+            .iter()
+            .filter(|block| {
+                known.is_none_or(|known| {
+                    let key = normalize(block);
+                    !known.blocks.iter().any(|x| normalize(x) == key)
+                })
+            })
+            .copied()
+            .collect();
+
+        if added.is_empty() {
+            continue;
+        }
+
+        output.extend(section.heading);
+        output.extend(added);
+    }
+
+    if output.is_empty() {
+        None
+    } else {
+        Some(output.join("\n\n"))
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{new_content, split_blocks};
+    use crate::template::puzzle_html::to_markdown;
+
+    static PART_ONE: &str = include_str!(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/data/fixtures/puzzle-part-1.md"
+    ));
+
+    static PART_TWO: &str = include_str!(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/data/fixtures/puzzle-part-2.md"
+    ));
+
+    #[test]
+    fn keeps_code_blocks_whole() {
+        assert_eq!(
+            split_blocks("a\nb\n\n```\n1\n\n2\n```\n\nc\n"),
+            vec!["a\nb", "```\n1\n\n2\n```", "c"]
+        );
+    }
+
+    #[test]
+    fn finds_part_two() {
+        let added = new_content(PART_ONE, PART_TWO).unwrap();
+        assert_eq!(
+            added,
+            [
+                "\\--- Day 1: Historian Hysteria ---\n----------",
+                "Your puzzle answer was `1234`.",
+                "\\--- Part Two ---\n----------",
+                "Figure out *exactly* how often each number from the left list appears:",
+                "* `3` appears *three* times\n* `4` appears once",
+                "Your puzzle answer was `5678`.",
+            ]
+            .join("\n\n")
+        );
+        assert_eq!(new_content(PART_TWO, PART_TWO), None);
+    }
+
+    #[test]
+    fn ignores_formatting_differences() {
+        let unescaped = PART_ONE.replace("2 \\* 3", "2 * 3");
+        assert_eq!(new_content(PART_ONE, &unescaped), None);
+
+        let page = include_str!(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/data/fixtures/puzzle-part-1.html"
+        ));
+        assert_eq!(new_content(PART_ONE, &to_markdown(page)), None);
+    }
+}
@@ -0,0 +1,246 @@
+/// Converts the HTML page of a puzzle to the markdown subset understood by `puzzle_markdown`.
+/// Only the puzzle articles and the "Your puzzle answer was" paragraphs are kept.
+use std::mem;
+
+#[derive(Debug, PartialEq, Eq)]
+enum Token<'a> {
+    Text(&'a str),
+    Open(&'a str, &'a str),
+    Close(&'a str),
+}
+
+/// Splits HTML into text, opening and closing tags. Comments, doctypes and self-closing tags are dropped.
+fn tokenize(html: &str) -> Vec<Token<'_>> {
+    let mut tokens = vec![];
+    let mut rest = html;
+
+    while !rest.is_empty() {
+        let Some(start) = rest.find('<') else {
+            tokens.push(Token::Text(rest));
+            break;
+        };
+
+        if start > 0 {
+            tokens.push(Token::Text(&rest[..start]));
+        }
+
+        rest = &rest[start..];
+
+        if let Some(comment) = rest.strip_prefix("<!--") {
+            rest = comment.find("-->").map_or("", |end| &comment[end + 3..]);
+            continue;
+        }
+
+        let Some(end) = rest.find('>') else {
+            tokens.push(Token::Text(rest));
+            break;
+        };
+
+        let tag = &rest[1..end];
+        rest = &rest[end + 1..];
+
+        if tag.starts_with('!') || tag.ends_with('/') {
+            continue;
+        }
+
+        if let Some(name) = tag.strip_prefix('/') {
+            tokens.push(Token::Close(name.trim()));
+        } else {
+            let (name, attrs) = tag.split_once(char::is_whitespace).unwrap_or((tag, ""));
+            tokens.push(Token::Open(name, attrs));
+        }
+    }
+
+    tokens
+}
+
+fn get_attr<'a>(attrs: &'a str, name: &str) -> Option<&'a str> {
+    let start = attrs.find(&format!("{name}=\""))? + name.len() + 2;
+    let end = attrs[start..].find('"')? + start;
+    Some(&attrs[start..end])
+}
+
+fn decode_entities(s: &str) -> String {
+    s.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&apos;", "'")
+        .replace("&nbsp;", " ")
+        .replace("&amp;", "&")
+}
+
+/// Escapes characters that would otherwise be read as markdown emphasis.
+fn escape(s: &str) -> String {
+    s.replace('\\', "\\\\").replace('*', "\\*")
+}
+
+#[derive(Default)]
+struct Converter {
+    blocks: Vec<String>,
+    buffer: String,
+    list: Vec<String>,
+    links: Vec<String>,
+    in_main: bool,
+    in_article: bool,
+    in_pre: bool,
+}
+
+impl Converter {
+    fn take_buffer(&mut self) -> String {
+        mem::take(&mut self.buffer).trim().to_string()
+    }
+
+    fn push_text(&mut self, text: &str) {
+        let text = decode_entities(text);
+
+        if self.in_pre {
+            self.buffer.push_str(&text);
+            return;
+        }
+
+        let collapsed = text.split_whitespace().collect::<Vec<_>>().join(" ");
+        if text.starts_with(char::is_whitespace) && !self.buffer.ends_with(' ') {
+            self.buffer.push(' ');
+        }
+        self.buffer.push_str(&escape(&collapsed));
+        if text.ends_with(char::is_whitespace) && !collapsed.is_empty() {
+            self.buffer.push(' ');
+        }
+    }
+
+    fn open(&mut self, name: &str, attrs: &str) {
+        match name {
+            "main" => self.in_main = true,
+            "article" => self.in_article = true,
+            "h2" | "p" | "li" => self.buffer.clear(),
+            "pre" => {
+                self.buffer.clear();
+                self.in_pre = true;
+            }
+            "em" if !self.in_pre => self.buffer.push('*'),
+            "code" if !self.in_pre => self.buffer.push('`'),
+            "a" if !self.in_pre => {
+                self.links
+                    .push(get_attr(attrs, "href").unwrap_or("").into());
+                self.buffer.push('[');
+            }
+            _ => {}
+        }
+    }
+
+    fn close(&mut self, name: &str) {
+        match name {
+            "main" => self.in_main = false,
+            "article" => self.in_article = false,
+            "h2" => {
+                let heading = self.take_buffer();
+                self.blocks.push(format!("## {heading}"));
+            }
+            "p" => {
+                let paragraph = self.take_buffer();
+                if self.in_article || paragraph.starts_with("Your puzzle answer was") {
+                    self.blocks.push(paragraph);
+                }
+            }
+            "pre" => {
+                self.in_pre = false;
+                let code = mem::take(&mut self.buffer);
+                self.blocks
+                    .push(format!("```\n{}\n```", code.trim_end_matches('\n')));
+            }
+            "li" => {
+                let item = self.take_buffer();
+                self.list.push(format!("* {item}"));
+            }
+            "ul" => {
+                let list = mem::take(&mut self.list);
+                self.blocks.push(list.join("\n"));
+            }
+            "em" if !self.in_pre => self.buffer.push('*'),
+            "code" if !self.in_pre => self.buffer.push('`'),
+            "a" if !self.in_pre => {
+                let href = self.links.pop().unwrap_or_default();
+                self.buffer.push_str(&format!("]({href})"));
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Converts a puzzle page to markdown.
+pub fn to_markdown(html: &str) -> String {
+    let mut converter = Converter::default();
+
+    for token in tokenize(html) {
+        match token {
+            Token::Open("main", _) => converter.in_main = true,
+            _ if !converter.in_main => {}
+            Token::Open(name, attrs) => converter.open(name, attrs),
+            Token::Close(name) => converter.close(name),
+            Token::Text(text) => converter.push_text(text),
+        }
+    }
+
+    let mut markdown = converter.blocks.join("\n\n");
+    markdown.push('\n');
+    markdown
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{to_markdown, tokenize, Token};
+
+    static FIXTURE: &str = include_str!(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/data/fixtures/puzzle-part-2.html"
+    ));
+
+    #[test]
+    fn tokenizes_html() {
+        assert_eq!(
+            tokenize("<p class=\"x\">a<!-- c --><br/><em>b</em></p>"),
+            vec![
+                Token::Open("p", "class=\"x\""),
+                Token::Text("a"),
+                Token::Open("em", ""),
+                Token::Text("b"),
+                Token::Close("em"),
+                Token::Close("p"),
+            ]
+        );
+    }
+
+    #[test]
+    fn converts_puzzle_page() {
+        let expected = [
+            "## --- Day 1: Historian Hysteria ---",
+            "",
+            "The *Chief Historian* is always present. Check the [list](/2024/day/1/input), 2 \\* 3 < 7.",
+            "",
+            "```",
+            "3   4",
+            "4   3",
+            "```",
+            "",
+            "In the example above, this is `*11*`.",
+            "",
+            "Your puzzle answer was `1234`.",
+            "",
+            "## --- Part Two ---",
+            "",
+            "Figure out *exactly* how often each number from the left list appears:",
+            "",
+            "* `3` appears *three* times",
+            "* `4` appears once",
+            "",
+            "Your puzzle answer was `5678`.",
+            "",
+        ]
+        .join("\n");
+
+        assert_eq!(to_markdown(FIXTURE), expected);
+    }
+}
@@ -0,0 +1,319 @@
+/// Renders the puzzle markdown stored by aoc-cli (`data/puzzles/NN.md`) for the terminal.
+/// Only the subset of markdown that aoc-cli produces from the puzzle HTML is supported.
+use crate::template::{ANSI_BOLD, ANSI_RESET};
+
+const ANSI_HEADING: &str = "\x1b[1;32m";
+const ANSI_EM: &str = "\x1b[1;97m";
+const ANSI_CODE: &str = "\x1b[36m";
+const ANSI_ANSWER: &str = "\x1b[1;33m";
+const ANSI_LINK: &str = "\x1b[4m";
+
+static PART_TWO_HEADING: &str = "--- Part Two ---";
+
+/// Returns the section of the puzzle for `part`: part one ends where part two starts.
+/// Returns `None` if the requested part is not contained in the puzzle (yet).
+pub fn select_part(markdown: &str, part: Option<u8>) -> Option<&str> {
+    let part_two_start = find_part_two(markdown);
+
+    match (part, part_two_start) {
+        (None, _) => Some(markdown),
+        (Some(1), Some(start)) => Some(&markdown[..start]),
+        (Some(1), None) => Some(markdown),
+        (Some(2), Some(start)) => Some(&markdown[start..]),
+        _ => None,
+    }
+}
+
+/// Byte offset of the line that contains the part two heading.
+fn find_part_two(markdown: &str) -> Option<usize> {
+    let mut offset = 0;
+
+    for line in markdown.split_inclusive('\n') {
+        if line.contains(PART_TWO_HEADING) {
+            return Some(offset);
+        }
+        offset += line.len();
+    }
+
+    None
+}
+
+/// Renders puzzle markdown as ANSI-formatted text.
+pub fn render(markdown: &str) -> String {
+    let lines: Vec<&str> = markdown.lines().collect();
+    let mut output: Vec<String> = vec![];
+    let mut in_code_block = false;
+    let mut i = 0;
+
+    while i < lines.len() {
+        let line = lines[i];
+        i += 1;
+
+        if line.trim_start().starts_with("```") {
+            in_code_block = !in_code_block;
+            continue;
+        }
+
+        if in_code_block {
+            output.push(format!("    {ANSI_CODE}{line}{ANSI_RESET}"));
+            continue;
+        }
+
+        // setext headings, e.g. `\--- Day 1: Foo ---` underlined with `----------`.
+        if !line.trim().is_empty() && lines.get(i).is_some_and(|next| is_underline(next)) {
+            i += 1;
+            output.push(render_heading(line));
+            continue;
+        }
+
+        if let Some(heading) = line.strip_prefix('#') {
+            output.push(render_heading(heading.trim_start_matches('#')));
+            continue;
+        }
+
+        if let Some(item) = line.strip_prefix("* ").or_else(|| line.strip_prefix("- ")) {
+            output.push(format!("  • {}", render_inline(item, ANSI_CODE)));
+            continue;
+        }
+
+        let code_style = if line.starts_with("Your puzzle answer was") {
+            ANSI_ANSWER
+        } else {
+            ANSI_CODE
+        };
+
+        output.push(render_inline(line, code_style));
+    }
+
+    output.join("\n")
+}
+
+fn is_underline(line: &str) -> bool {
+    let line = line.trim();
+    line.len() >= 3 && (line.chars().all(|c| c == '-') || line.chars().all(|c| c == '='))
+}
+
+fn render_heading(line: &str) -> String {
+    let text = strip_tags(&unescape(line.trim()));
+    format!("{ANSI_HEADING}{text}{ANSI_RESET}")
+}
+
+/// Renders emphasis, inline code, links and the raw `<em>` / `<code>` tags aoc-cli sometimes leaves in.
+fn render_inline(line: &str, code_style: &'static str) -> String {
+    let mut output = String::new();
+    let mut styles: Vec<&str> = vec![];
+    let mut rest = line;
+
+    let push_style = |output: &mut String, styles: &mut Vec<&str>, style| {
+        styles.push(style);
+        output.push_str(style);
+    };
+
+    let pop_style = |output: &mut String, styles: &mut Vec<&str>| {
+        styles.pop();
+        output.push_str(ANSI_RESET);
+        output.extend(styles.iter().copied());
+    };
+
+    while let Some(c) = rest.chars().next() {
+        if let Some(tag) = ["<em>", "</em>", "<code>", "</code>"]
+            .iter()
+            .find(|tag| rest.starts_with(**tag))
+        {
+            match *tag {
+                "<em>" => push_style(&mut output, &mut styles, ANSI_EM),
+                "<code>" => push_style(&mut output, &mut styles, code_style),
+                _ => pop_style(&mut output, &mut styles),
+            }
+            rest = &rest[tag.len()..];
+            continue;
+        }
+
+        match c {
+            '\\' => {
+                let escaped = rest[1..].chars().next();
+                if let Some(escaped) = escaped {
+                    output.push(escaped);
+                    rest = &rest[1 + escaped.len_utf8()..];
+                } else {
+                    output.push('\\');
+                    rest = &rest[1..];
+                }
+            }
+            '`' => {
+                let Some(end) = rest[1..].find('`') else {
+                    output.push(c);
+                    rest = &rest[1..];
+                    continue;
+                };
+                let code = &rest[1..=end];
+                // html2md renders `<code><em>42</em></code>` as `` `*42*` ``.
+                let emphasized = code
+                    .strip_prefix('*')
+                    .and_then(|x| x.strip_suffix('*'))
+                    .filter(|x| !x.is_empty());
+                let style = match emphasized {
+                    Some(_) if code_style != ANSI_ANSWER => ANSI_EM,
+                    _ => code_style,
+                };
+                push_style(&mut output, &mut styles, style);
+                output.push_str(emphasized.unwrap_or(code));
+                pop_style(&mut output, &mut styles);
+                rest = &rest[end + 2..];
+            }
+            '*' => {
+                let marker = if rest.starts_with("**") {
+                    &rest[..2]
+                } else {
+                    &rest[..1]
+                };
+                let style = if marker.len() == 2 {
+                    ANSI_BOLD
+                } else {
+                    ANSI_EM
+                };
+                if styles.last() == Some(&style) {
+                    pop_style(&mut output, &mut styles);
+                } else if rest[marker.len()..].contains(marker) {
+                    push_style(&mut output, &mut styles, style);
+                } else {
+                    output.push_str(marker);
+                }
+                rest = &rest[marker.len()..];
+            }
+            '[' => {
+                let link = rest.find("](").and_then(|mid| {
+                    let end = rest[mid..].find(')')? + mid;
+                    Some((&rest[1..mid], end))
+                });
+                match link {
+                    Some((text, end)) => {
+                        push_style(&mut output, &mut styles, ANSI_LINK);
+                        output.push_str(&render_inline(text, code_style));
+                        pop_style(&mut output, &mut styles);
+                        rest = &rest[end + 1..];
+                    }
+                    None => {
+                        output.push(c);
+                        rest = &rest[1..];
+                    }
+                }
+            }
+            _ => {
+                output.push(c);
+                rest = &rest[c.len_utf8()..];
+            }
+        }
+    }
+
+    if !styles.is_empty() {
+        output.push_str(ANSI_RESET);
+    }
+
+    output
+}
+
+fn unescape(s: &str) -> String {
+    let mut output = String::new();
+    let mut chars = s.chars();
+    while let Some(c) = chars.next() {
+        if c == '\\' {
+            if let Some(next) = chars.next() {
+                output.push(next);
+            }
+        } else {
+            output.push(c);
+        }
+    }
+    output
+}
+
+fn strip_tags(s: &str) -> String {
+    ["<em>", "</em>", "<code>", "</code>"]
+        .iter()
+        .fold(s.to_string(), |acc, tag| acc.replace(tag, ""))
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{
+        render, render_inline, select_part, ANSI_ANSWER, ANSI_CODE, ANSI_EM, ANSI_HEADING,
+        ANSI_LINK,
+    };
+    use crate::template::{ANSI_BOLD, ANSI_RESET};
+
+    static PUZZLE: &str = "\\--- Day 1: Historian Hysteria ---
+----------
+
+The lists are *not* very similar.
+
+```
+3   4
+4   3
+```
+
+Adding these up produces a total of `*11*`.
+
+Your puzzle answer was `1234`.
+
+\\--- Part Two ---
+----------
+
+This time, you'll need to figure out *exactly* how often each number appears.
+";
+
+    #[test]
+    fn renders_headings() {
+        let rendered = render(PUZZLE);
+        let lines: Vec<&str> = rendered.lines().collect();
+        assert_eq!(
+            lines[0],
+            format!("{ANSI_HEADING}--- Day 1: Historian Hysteria ---{ANSI_RESET}")
+        );
+        assert!(!rendered.contains("----------"));
+    }
+
+    #[test]
+    fn renders_code_blocks() {
+        let rendered = render(PUZZLE);
+        assert!(rendered.contains(&format!("    {ANSI_CODE}3   4{ANSI_RESET}")));
+        assert!(!rendered.contains("```"));
+    }
+
+    #[test]
+    fn highlights_emphasis_and_answers() {
+        assert_eq!(
+            render_inline("is *not* `x` and `*11*`", ANSI_CODE),
+            format!(
+                "is {ANSI_EM}not{ANSI_RESET} {ANSI_CODE}x{ANSI_RESET} and {ANSI_EM}11{ANSI_RESET}"
+            )
+        );
+        assert_eq!(
+            render_inline("a <em>b</em> **c**", ANSI_CODE),
+            format!("a {ANSI_EM}b{ANSI_RESET} {ANSI_BOLD}c{ANSI_RESET}")
+        );
+        assert!(render(PUZZLE).contains(&format!(
+            "Your puzzle answer was {ANSI_ANSWER}1234{ANSI_RESET}."
+        )));
+    }
+
+    #[test]
+    fn renders_links_and_escapes() {
+        assert_eq!(
+            render_inline("see [the list](/2024/day/1) \\*x\\* 2 * 3", ANSI_CODE),
+            format!("see {ANSI_LINK}the list{ANSI_RESET} *x* 2 * 3")
+        );
+    }
+
+    #[test]
+    fn selects_parts() {
+        let part_one = select_part(PUZZLE, Some(1)).unwrap();
+        let part_two = select_part(PUZZLE, Some(2)).unwrap();
+        assert!(part_one.contains("Day 1") && !part_one.contains("Part Two"));
+        assert!(part_two.starts_with("\\--- Part Two ---"));
+        assert_eq!(select_part(PUZZLE, None), Some(PUZZLE));
+        assert_eq!(select_part(part_one, Some(2)), None);
+    }
+}
@@ -1,11 +1,205 @@
 /// Module that updates the readme me with timing information.
 /// The approach taken is similar to how `aoc-readme-stars` handles this.
-use std::{fs, io};
+use std::path::{Component, Path};
+use std::{env, fs, io, str::FromStr};
 
-use crate::template::timings::Timings;
+use crate::template::line_diff;
+use crate::template::peak_alloc::format_bytes;
+use crate::template::timings::{format_nanos, PartTiming, Timing, Timings};
 use crate::template::Day;
 
 static MARKER: &str = "<!--- benchmarking table --->";
+static COLUMNS_ENV_VAR: &str = "AOC_BENCHMARK_COLUMNS";
+static TARGETS_ENV_VAR: &str = "AOC_BENCHMARK_TARGETS";
+const SHARE_BAR_WIDTH: usize = 10;
+
+/// Optional columns of the benchmark table, in addition to the mean time of each part.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Column {
+    Median,
+    Spread,
+    Parse,
+    Memory,
+    Samples,
+    Share,
+}
+
+impl FromStr for Column {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim() {
+            "median" => Ok(Column::Median),
+            "spread" => Ok(Column::Spread),
+            "parse" => Ok(Column::Parse),
+            "memory" => Ok(Column::Memory),
+            "samples" => Ok(Column::Samples),
+            "share" => Ok(Column::Share),
+            other => Err(format!("unknown benchmark column \"{other}\".")),
+        }
+    }
+}
+
+impl Column {
+    fn header(self) -> &'static str {
+        match self {
+            Column::Median => "Median",
+            Column::Spread => "Spread",
+            Column::Parse => "Parse",
+            Column::Memory => "Memory",
+            Column::Samples => "Samples",
+            Column::Share => "Share",
+        }
+    }
+
+    fn cell(self, timing: &Timing, total_nanos: f64) -> String {
+        let per_part = |format: &dyn Fn(&PartTiming) -> Option<String>| {
+            let part_1 = timing.part_1.as_ref().and_then(format);
+            let part_2 = timing.part_2.as_ref().and_then(format);
+            format!(
+                "{} / {}",
+                part_1.unwrap_or_else(|| "-".into()),
+                part_2.unwrap_or_else(|| "-".into())
+            )
+        };
+
+        match self {
+            Column::Median => {
+                per_part(&|x| x.median_nanos.map(|x| format!("`{}`", format_nanos(x))))
+            }
+            Column::Spread => {
+                per_part(&|x| x.spread_nanos.map(|x| format!("`±{}`", format_nanos(x))))
+            }
+            Column::Samples => per_part(&|x| x.samples.map(|x| x.to_string())),
+            Column::Parse => timing
+                .parse_nanos
+                .map_or_else(|| "-".into(), |x| format!("`{}`", format_nanos(x))),
+            Column::Memory => timing
+                .peak_memory()
+                .map_or_else(|| "-".into(), |x| format!("`{}`", format_bytes(x))),
+            Column::Share => render_share(timing.total_nanos(), total_nanos),
+        }
+    }
+}
+
+/// Reads the optional columns from `AOC_BENCHMARK_COLUMNS`, e.g. `median,spread,share`.
+pub fn get_columns() -> Vec<Column> {
+    let Ok(value) = env::var(COLUMNS_ENV_VAR) else {
+        return vec![];
+    };
+
+    value
+        .split(',')
+        .filter(|x| !x.trim().is_empty())
+        .filter_map(|x| match Column::from_str(x) {
+            Ok(column) => Some(column),
+            Err(e) => {
+                eprintln!("Ignoring ${COLUMNS_ENV_VAR} entry: {e}");
+                None
+            }
+        })
+        .collect()
+}
+
+/// A file that contains a benchmark table, e.g. `docs/benchmarks.md marker=timings heading=3`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Target {
+    pub path: String,
+    /// Comment that encloses the table.
+    pub marker: String,
+    /// Level of the table heading, `2` for `## Benchmarks`.
+    pub heading_level: usize,
+}
+
+impl Default for Target {
+    fn default() -> Self {
+        Target {
+            path: "README.md".into(),
+            marker: MARKER.into(),
+            heading_level: 2,
+        }
+    }
+}
+
+impl FromStr for Target {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut options = s.split_whitespace();
+
+        let mut target = Target {
+            path: options
+                .next()
+                .ok_or("expected benchmark target to name a file.")?
+                .into(),
+            ..Target::default()
+        };
+
+        for option in options {
+            match option.split_once('=') {
+                Some(("marker", name)) if !name.is_empty() => {
+                    target.marker = format!("<!--- {} --->", name.replace('_', " "));
+                }
+                Some(("heading", level)) => {
+                    target.heading_level = level
+                        .parse()
+                        .ok()
+                        .filter(|x| (1..=6).contains(x))
+                        .ok_or(format!(
+                            "expected heading level to be 1 to 6, got \"{level}\"."
+                        ))?;
+                }
+                _ => return Err(format!("unknown benchmark target option \"{option}\".")),
+            }
+        }
+
+        Ok(target)
+    }
+}
+
+/// Reads the files to update from `AOC_BENCHMARK_TARGETS`, e.g. `README.md, docs/benchmarks.md heading=3`.
+/// Defaults to the readme.
+pub fn get_targets() -> Vec<Target> {
+    let Ok(value) = env::var(TARGETS_ENV_VAR) else {
+        return vec![Target::default()];
+    };
+
+    value
+        .split(',')
+        .filter(|x| !x.trim().is_empty())
+        .filter_map(|x| match Target::from_str(x) {
+            Ok(target) => Some(target),
+            Err(e) => {
+                eprintln!("Ignoring ${TARGETS_ENV_VAR} entry: {e}");
+                None
+            }
+        })
+        .collect()
+}
+
+/// Renders the share of `nanos` in `total_nanos` as a bar of eighth blocks and a percentage.
+#[allow(
+    clippy::cast_possible_truncation,
+    clippy::cast_sign_loss,
+    clippy::cast_precision_loss
+)]
+fn render_share(nanos: f64, total_nanos: f64) -> String {
+    const BLOCKS: [char; 8] = ['▏', '▎', '▍', '▌', '▋', '▊', '▉', '█'];
+
+    if total_nanos <= 0.0 {
+        return "-".into();
+    }
+
+    let share = nanos / total_nanos;
+    let eighths = (share * (SHARE_BAR_WIDTH * 8) as f64).round() as usize;
+
+    let mut bar = "█".repeat(eighths / 8);
+    if !eighths.is_multiple_of(8) {
+        bar.push(BLOCKS[eighths % 8 - 1]);
+    }
+
+    format!("`{bar:<SHARE_BAR_WIDTH$}` {:.1}%", share * 100.0)
+}
 
 #[allow(dead_code)]
 #[derive(Debug)]
@@ -21,8 +215,8 @@ impl From<std::io::Error> for Error {
 }
 
 pub struct TablePosition {
-    pos_start: usize,
-    pos_end: usize,
+    pub pos_start: usize,
+    pub pos_end: usize,
 }
 
 #[must_use]
@@ -30,8 +224,24 @@ pub fn get_path_for_bin(day: Day) -> String {
     format!("./src/bin/{day}.rs")
 }
 
-fn locate_table(readme: &str) -> Result<TablePosition, Error> {
-    let matches: Vec<_> = readme.match_indices(MARKER).collect();
+/// Path of the solution of `day`, relative to the file at `target`.
+fn get_link_for_bin(target: &str, day: Day) -> String {
+    let depth = Path::new(target).parent().map_or(0, |x| {
+        x.components()
+            .filter(|x| matches!(x, Component::Normal(_)))
+            .count()
+    });
+
+    if depth == 0 {
+        get_path_for_bin(day)
+    } else {
+        format!("{}src/bin/{day}.rs", "../".repeat(depth))
+    }
+}
+
+/// Locates the table enclosed by a pair of `marker` comments.
+pub fn locate_table(readme: &str, marker: &str) -> Result<TablePosition, Error> {
+    let matches: Vec<_> = readme.match_indices(marker).collect();
 
     if matches.len() > 2 {
         return Err(Error::Parser(
@@ -52,77 +262,132 @@ fn locate_table(readme: &str) -> Result<TablePosition, Error> {
     Ok(TablePosition { pos_start, pos_end })
 }
 
-fn construct_table(prefix: &str, timings: Timings, total_millis: f64) -> String {
-    let header = format!("{prefix} Benchmarks");
+fn construct_table(
+    target: &Target,
+    timings: &Timings,
+    total_millis: f64,
+    columns: &[Column],
+) -> String {
+    let header = format!("{} Benchmarks", "#".repeat(target.heading_level));
+    let total_nanos = timings.data.iter().map(Timing::total_nanos).sum::<f64>();
+
+    let column_headers: String = columns
+        .iter()
+        .map(|x| format!(" {} |", x.header()))
+        .collect();
+
+    let column_alignments = " :---: |".repeat(columns.len());
 
     let mut lines: Vec<String> = vec![
-        MARKER.into(),
+        target.marker.clone(),
         header,
         String::new(),
-        "| Day | Part 1 | Part 2 |".into(),
-        "| :---: | :---: | :---:  |".into(),
+        format!("| Day | Part 1 | Part 2 |{column_headers}"),
+        format!("| :---: | :---: | :---:  |{column_alignments}"),
     ];
 
-    for timing in timings.data {
-        let path = get_path_for_bin(timing.day);
+    for timing in &timings.data {
+        let path = get_link_for_bin(&target.path, timing.day);
+
+        let cells: String = columns
+            .iter()
+            .map(|x| format!(" {} |", x.cell(timing, total_nanos)))
+            .collect();
+
         lines.push(format!(
-            "| [Day {}]({}) | `{}` | `{}` |",
+            "| [Day {}]({}) | `{}` | `{}` |{}",
             timing.day.into_inner(),
             path,
-            timing.part_1.unwrap_or_else(|| "-".into()),
-            timing.part_2.unwrap_or_else(|| "-".into())
+            timing.format_part(1),
+            timing.format_part(2),
+            cells
         ));
     }
 
     lines.push(String::new());
     lines.push(format!("**Total: {total_millis:.2}ms**"));
-    lines.push(MARKER.into());
+    lines.push(target.marker.clone());
 
     lines.join("\n")
 }
 
-fn update_content(s: &mut String, timings: Timings, total_millis: f64) -> Result<(), Error> {
-    let positions = locate_table(s)?;
-    let table = construct_table("##", timings, total_millis);
+fn update_content(
+    s: &mut String,
+    target: &Target,
+    timings: &Timings,
+    total_millis: f64,
+    columns: &[Column],
+) -> Result<(), Error> {
+    let positions = locate_table(s, &target.marker)?;
+    let table = construct_table(target, timings, total_millis, columns);
     s.replace_range(positions.pos_start..positions.pos_end, &table);
     Ok(())
 }
 
-pub fn update(timings: Timings) -> Result<(), Error> {
-    let path = "README.md";
-    let mut readme = String::from_utf8_lossy(&fs::read(path)?).to_string();
+/// Updates the benchmark tables of all targets. With `dry_run`, prints the changes instead of writing them.
+/// Every target is attempted, failures are reported and returned as the first error.
+pub fn update(timings: &Timings, dry_run: bool) -> Result<(), Error> {
     let total_millis = timings.total_millis();
-    update_content(&mut readme, timings, total_millis)?;
-    fs::write(path, &readme)?;
+    let columns = get_columns();
+    let mut result = Ok(());
+
+    for target in get_targets() {
+        let updated = update_target(&target, timings, total_millis, &columns, dry_run);
+
+        if let Err(e) = updated {
+            eprintln!("Failed to update benchmarks in \"{}\": {e:?}", target.path);
+            result = result.and(Err(e));
+        }
+    }
+
+    result
+}
+
+fn update_target(
+    target: &Target,
+    timings: &Timings,
+    total_millis: f64,
+    columns: &[Column],
+    dry_run: bool,
+) -> Result<(), Error> {
+    let original = String::from_utf8_lossy(&fs::read(&target.path)?).to_string();
+    let mut content = original.clone();
+    update_content(&mut content, target, timings, total_millis, columns)?;
+
+    if dry_run {
+        match line_diff::render(&target.path, &original, &content) {
+            Some(diff) => print!("{diff}"),
+            None => println!("No changes to \"{}\".", target.path),
+        }
+    } else {
+        fs::write(&target.path, &content)?;
+    }
+
     Ok(())
 }
 
 #[cfg(feature = "test_lib")]
 mod tests {
-    use super::{update_content, MARKER};
+    use std::str::FromStr;
+
+    use super::{get_link_for_bin, render_share, update_content, Column, Target, MARKER};
+    use crate::template::timings::PartTiming;
     use crate::{day, template::timings::Timing, template::timings::Timings};
 
+    fn get_timing(day: crate::template::Day, part_1: f64, part_2: f64) -> Timing {
+        Timing {
+            part_1: Some(PartTiming::solved(part_1)),
+            part_2: Some(PartTiming::solved(part_2)),
+            ..Timing::new(day)
+        }
+    }
+
     fn get_mock_timings() -> Timings {
         Timings {
             data: vec![
-                Timing {
-                    day: day!(1),
-                    part_1: Some("10ms".into()),
-                    part_2: Some("20ms".into()),
-                    total_nanos: 3e+10,
-                },
-                Timing {
-                    day: day!(2),
-                    part_1: Some("30ms".into()),
-                    part_2: Some("40ms".into()),
-                    total_nanos: 7e+10,
-                },
-                Timing {
-                    day: day!(4),
-                    part_1: Some("40ms".into()),
-                    part_2: Some("50ms".into()),
-                    total_nanos: 9e+10,
-                },
+                get_timing(day!(1), 1e+7, 2e+7),
+                get_timing(day!(2), 3e+7, 4e+7),
+                get_timing(day!(4), 4e+7, 5e+7),
             ],
         }
     }
@@ -131,28 +396,28 @@ mod tests {
     #[should_panic]
     fn errors_if_marker_not_present() {
         let mut s = "# readme".to_string();
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, &Target::default(), &get_mock_timings(), 190.0, &[]).unwrap();
     }
 
     #[test]
     #[should_panic]
     fn errors_if_too_many_markers_present() {
         let mut s = format!("{} {} {}", MARKER, MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, &Target::default(), &get_mock_timings(), 190.0, &[]).unwrap();
     }
 
     #[test]
     fn updates_empty_benchmarks() {
         let mut s = format!("foo\nbar\n{}{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, &Target::default(), &get_mock_timings(), 190.0, &[]).unwrap();
         assert_eq!(s.contains("## Benchmarks"), true);
     }
 
     #[test]
     fn updates_existing_benchmarks() {
         let mut s = format!("foo\nbar\n{}{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, &Target::default(), &get_mock_timings(), 190.0, &[]).unwrap();
+        update_content(&mut s, &Target::default(), &get_mock_timings(), 190.0, &[]).unwrap();
         assert_eq!(s.matches(MARKER).collect::<Vec<&str>>().len(), 2);
         assert_eq!(s.matches("## Benchmarks").collect::<Vec<&str>>().len(), 1);
     }
@@ -160,7 +425,7 @@ mod tests {
     #[test]
     fn format_benchmarks() {
         let mut s = format!("foo\nbar\n{}\n{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, &Target::default(), &get_mock_timings(), 190.0, &[]).unwrap();
         let expected = [
             "foo",
             "bar",
@@ -169,9 +434,9 @@ mod tests {
             "",
             "| Day | Part 1 | Part 2 |",
             "| :---: | :---: | :---:  |",
-            "| [Day 1](./src/bin/01.rs) | `10ms` | `20ms` |",
-            "| [Day 2](./src/bin/02.rs) | `30ms` | `40ms` |",
-            "| [Day 4](./src/bin/04.rs) | `40ms` | `50ms` |",
+            "| [Day 1](./src/bin/01.rs) | `10.0ms` | `20.0ms` |",
+            "| [Day 2](./src/bin/02.rs) | `30.0ms` | `40.0ms` |",
+            "| [Day 4](./src/bin/04.rs) | `40.0ms` | `50.0ms` |",
             "",
             "**Total: 190.00ms**",
             "<!--- benchmarking table --->",
@@ -180,4 +445,95 @@ mod tests {
         .join("\n");
         assert_eq!(s, expected);
     }
+
+    #[test]
+    fn format_benchmarks_with_columns() {
+        let mut timings = get_mock_timings();
+        timings.data[0].parse_nanos = Some(1500.0);
+        timings.data[0].part_1 = Some(PartTiming {
+            samples: Some(100),
+            median_nanos: Some(9_500_000.0),
+            spread_nanos: Some(250_000.0),
+            peak_memory: Some(2048),
+            ..PartTiming::solved(1e+7)
+        });
+
+        let mut s = format!("{}\n{}", MARKER, MARKER);
+        let columns = [
+            Column::Median,
+            Column::Spread,
+            Column::Parse,
+            Column::Memory,
+            Column::Samples,
+            Column::Share,
+        ];
+        update_content(&mut s, &Target::default(), &timings, 190.0, &columns).unwrap();
+        let lines: Vec<&str> = s.lines().collect();
+
+        assert_eq!(
+            lines[3],
+            "| Day | Part 1 | Part 2 | Median | Spread | Parse | Memory | Samples | Share |"
+        );
+        assert_eq!(
+            lines[4],
+            "| :---: | :---: | :---:  | :---: | :---: | :---: | :---: | :---: | :---: |"
+        );
+        assert_eq!(
+            lines[5],
+            "| [Day 1](./src/bin/01.rs) | `10.0ms` | `20.0ms` | `9.5ms` / - | `±250.0µs` / - | `1.5µs` | `2.0 KiB` | 100 / - | `█▋        ` 15.8% |"
+        );
+        assert_eq!(
+            lines[6],
+            "| [Day 2](./src/bin/02.rs) | `30.0ms` | `40.0ms` | - / - | - / - | - | - | - / - | `███▋      ` 36.8% |"
+        );
+    }
+
+    #[test]
+    fn renders_share_bars() {
+        assert_eq!(render_share(1.0, 1.0), "`██████████` 100.0%");
+        assert_eq!(render_share(0.0, 1.0), "`          ` 0.0%");
+        assert_eq!(render_share(1.0, 0.0), "-");
+    }
+
+    #[test]
+    fn parses_columns() {
+        assert_eq!(Column::from_str(" share"), Ok(Column::Share));
+        assert!(Column::from_str("foo").is_err());
+    }
+
+    #[test]
+    fn format_benchmarks_for_targets() {
+        let target = Target::from_str("docs/perf.md marker=perf_table heading=3").unwrap();
+        let mut s = "<!--- perf table ---><!--- perf table --->".to_string();
+        update_content(&mut s, &target, &get_mock_timings(), 190.0, &[]).unwrap();
+        let lines: Vec<&str> = s.lines().collect();
+
+        assert_eq!(lines[0], "<!--- perf table --->");
+        assert_eq!(lines[1], "### Benchmarks");
+        assert_eq!(
+            lines[5],
+            "| [Day 1](../src/bin/01.rs) | `10.0ms` | `20.0ms` |"
+        );
+        assert_eq!(s.matches(MARKER).count(), 0);
+    }
+
+    #[test]
+    fn parses_targets() {
+        assert_eq!(Target::from_str(" README.md "), Ok(Target::default()));
+        assert!(Target::from_str("README.md heading=7").is_err());
+        assert!(Target::from_str("README.md color=red").is_err());
+        assert!(Target::from_str(" ").is_err());
+        assert_eq!(
+            get_link_for_bin("2023/README.md", crate::day!(4)),
+            "../src/bin/04.rs"
+        );
+        assert_eq!(
+            get_link_for_bin("./README.md", crate::day!(4)),
+            "./src/bin/04.rs"
+        );
+        assert_eq!(
+            get_link_for_bin("./docs/x.md", crate::day!(4)),
+            "../src/bin/04.rs"
+        );
+    }
 }
@@ -0,0 +1,165 @@
+/// Module that updates the readme with ⭐️ progress.
+/// The table has the same format as the one written by `aoc-readme-stars`, but is built from local data.
+use std::fs;
+
+use crate::template::answers::Answers;
+use crate::template::leaderboard::Member;
+use crate::template::readme_benchmarks::{locate_table, Error};
+use crate::template::{all_days, Day};
+
+static MARKER: &str = "<!--- advent_readme_stars table --->";
+
+/// Stars collected for a single day.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DayStars {
+    pub day: Day,
+    pub part_1: bool,
+    pub part_2: bool,
+}
+
+/// Combines locally known progress with the progress of a leaderboard member.
+/// Only days with at least one star are returned.
+pub fn collect_stars(answers: &Answers, member: Option<&Member>) -> Vec<DayStars> {
+    all_days()
+        .map(|day| {
+            let progress = member.and_then(|m| m.days.get(&day));
+            DayStars {
+                day,
+                part_1: answers.is_solved(day, 1) || progress.is_some(),
+                part_2: answers.is_solved(day, 2) || progress.is_some_and(|p| p.part_2.is_some()),
+            }
+        })
+        .filter(|stars| stars.part_1 || stars.part_2)
+        .collect()
+}
+
+fn construct_table(prefix: &str, year: u16, stars: &[DayStars]) -> String {
+    let star = |collected: bool| if collected { "⭐" } else { " " };
+
+    let mut lines: Vec<String> = vec![
+        MARKER.into(),
+        format!("{prefix} {year} Results"),
+        String::new(),
+        "| Day | Part 1 | Part 2 |".into(),
+        "| :---: | :---: | :---: |".into(),
+    ];
+
+    for day in stars {
+        lines.push(format!(
+            "| [Day {}](https://adventofcode.com/{year}/day/{}) | {} | {} |",
+            day.day.into_inner(),
+            day.day.into_inner(),
+            star(day.part_1),
+            star(day.part_2)
+        ));
+    }
+
+    lines.push(MARKER.into());
+    lines.join("\n")
+}
+
+fn update_content(s: &mut String, year: u16, stars: &[DayStars]) -> Result<(), Error> {
+    let positions = locate_table(s, MARKER)?;
+    let table = construct_table("##", year, stars);
+    s.replace_range(positions.pos_start..positions.pos_end, &table);
+    Ok(())
+}
+
+/// Renders the stars table without touching the readme.
+pub fn render(year: u16, stars: &[DayStars]) -> String {
+    construct_table("##", year, stars)
+}
+
+pub fn update(year: u16, stars: &[DayStars]) -> Result<(), Error> {
+    let path = "README.md";
+    let mut readme = String::from_utf8_lossy(&fs::read(path)?).to_string();
+    update_content(&mut readme, year, stars)?;
+    fs::write(path, &readme)?;
+    Ok(())
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use super::{collect_stars, update_content, DayStars, MARKER};
+    use crate::day;
+    use crate::template::answers::{Answers, SubmitOutcome};
+    use crate::template::leaderboard::{DayProgress, Member};
+
+    fn get_mock_stars() -> Vec<DayStars> {
+        vec![
+            DayStars {
+                day: day!(1),
+                part_1: true,
+                part_2: true,
+            },
+            DayStars {
+                day: day!(2),
+                part_1: true,
+                part_2: false,
+            },
+        ]
+    }
+
+    #[test]
+    fn collects_stars_from_answers_and_leaderboard() {
+        let mut answers = Answers::default();
+        answers.record(day!(1), 1, "1", SubmitOutcome::Correct);
+        answers.record(day!(3), 1, "1", SubmitOutcome::Incorrect);
+
+        let member = Member {
+            id: 1,
+            name: None,
+            stars: 3,
+            local_score: 0,
+            days: BTreeMap::from([
+                (
+                    day!(1),
+                    DayProgress {
+                        part_1: 0,
+                        part_2: Some(1),
+                    },
+                ),
+                (
+                    day!(2),
+                    DayProgress {
+                        part_1: 0,
+                        part_2: None,
+                    },
+                ),
+            ]),
+        };
+
+        assert_eq!(collect_stars(&answers, None).len(), 1);
+        assert_eq!(collect_stars(&answers, Some(&member)), get_mock_stars());
+    }
+
+    #[test]
+    #[should_panic]
+    fn errors_if_marker_not_present() {
+        let mut s = "# readme".to_string();
+        update_content(&mut s, 2024, &get_mock_stars()).unwrap();
+    }
+
+    #[test]
+    fn format_stars() {
+        let mut s = format!("foo\n{}\n## old table\n{}\nbar", MARKER, MARKER);
+        update_content(&mut s, 2024, &get_mock_stars()).unwrap();
+        update_content(&mut s, 2024, &get_mock_stars()).unwrap();
+        let expected = [
+            "foo",
+            "<!--- advent_readme_stars table --->",
+            "## 2024 Results",
+            "",
+            "| Day | Part 1 | Part 2 |",
+            "| :---: | :---: | :---: |",
+            "| [Day 1](https://adventofcode.com/2024/day/1) | ⭐ | ⭐ |",
+            "| [Day 2](https://adventofcode.com/2024/day/2) | ⭐ |   |",
+            "<!--- advent_readme_stars table --->",
+            "bar",
+        ]
+        .join("\n");
+        assert_eq!(s, expected);
+    }
+}
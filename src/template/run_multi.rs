@@ -4,10 +4,16 @@ use crate::template::{Day, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
 
 use super::{
     all_days,
-    timings::{Timing, Timings},
+    timings::{Provenance, Timing, Timings},
 };
 
-pub fn run_multi(days_to_run: &HashSet<Day>, is_release: bool, is_timed: bool) -> Option<Timings> {
+/// Runs the solutions of `days_to_run`. `bench_args` are passed on to timed solutions.
+pub fn run_multi(
+    days_to_run: &HashSet<Day>,
+    is_release: bool,
+    is_timed: bool,
+    bench_args: &[String],
+) -> Option<Timings> {
     let mut timings: Vec<Timing> = Vec::with_capacity(days_to_run.len());
 
     let mut need_space = false;
@@ -24,12 +30,17 @@ pub fn run_multi(days_to_run: &HashSet<Day>, is_release: bool, is_timed: bool) -
             println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
             println!("------");
 
-            let output = child_commands::run_solution(day, is_timed, is_release).unwrap();
+            let output =
+                child_commands::run_solution(day, is_timed, is_release, bench_args).unwrap();
 
             if output.is_empty() {
                 println!("Not solved.");
             } else {
-                let val = child_commands::parse_exec_time(&output, day);
+                let mut val = child_commands::parse_exec_time(&output, day);
+                // solutions only report their threads when benched, and other runs are not stored.
+                let threads = child_commands::parse_threads(&output)
+                    .unwrap_or_else(rayon::current_num_threads);
+                val.set_provenance(Provenance::current(is_release, threads));
                 timings.push(val);
             }
         });
@@ -68,6 +79,7 @@ pub fn get_path_for_bin(day: Day) -> String {
 /// This module encapsulates interaction with these binaries, both invoking them as well as parsing the timing output.
 pub mod child_commands {
     use super::{get_path_for_bin, Error};
+    use crate::template::timings::{parse_duration, PartTiming};
     use crate::template::Day;
     use std::{
         io::{BufRead, BufReader},
@@ -77,7 +89,12 @@ pub mod child_commands {
     };
 
     /// Run the solution bin for a given day
-    pub fn run_solution(day: Day, is_timed: bool, is_release: bool) -> Result<Vec<String>, Error> {
+    pub fn run_solution(
+        day: Day,
+        is_timed: bool,
+        is_release: bool,
+        bench_args: &[String],
+    ) -> Result<Vec<String>, Error> {
         // skip command invocation for days that have not been scaffolded yet.
         if !Path::new(&get_path_for_bin(day)).exists() {
             return Ok(vec![]);
@@ -94,6 +111,7 @@ pub mod child_commands {
             // mirror `--time` flag to child invocations.
             args.push("--");
             args.push("--time");
+            args.extend(bench_args.iter().map(String::as_str));
         }
 
         // spawn child command with piped stdout/stderr.
@@ -129,64 +147,98 @@ pub mod child_commands {
     }
 
     pub fn parse_exec_time(output: &[String], day: Day) -> super::Timing {
-        let mut timings = super::Timing {
-            day,
-            part_1: None,
-            part_2: None,
-            total_nanos: 0_f64,
-        };
-
-        output
-            .iter()
-            .filter_map(|l| {
-                if !l.contains(" samples)") {
-                    return None;
-                }
+        let mut timings = super::Timing::new(day);
 
-                let Some((timing_str, nanos)) = parse_time(l) else {
-                    eprintln!("Could not parse timings from line: {l}");
-                    return None;
-                };
-
-                let part = l.split(':').next()?;
-                Some((part, timing_str, nanos))
-            })
-            .for_each(|(part, timing_str, nanos)| {
-                if part.contains("Part 1") {
-                    timings.part_1 = Some(timing_str.into());
-                } else if part.contains("Part 2") {
-                    timings.part_2 = Some(timing_str.into());
-                }
+        for line in output {
+            let Some((label, _)) = line.split_once(':') else {
+                continue;
+            };
 
-                timings.total_nanos += nanos;
-            });
+            let is_unsolved = line.contains('✖');
+
+            if !is_unsolved && !line.contains(" samples") {
+                continue;
+            }
+
+            let part = if is_unsolved {
+                PartTiming::unsolved()
+            } else if let Some(part) = parse_time(line) {
+                part
+            } else {
+                eprintln!("Could not parse timings from line: {line}");
+                continue;
+            };
+
+            if label.contains("Parse") {
+                // parsing happens as part of the solutions, so it does not add to the total.
+                timings.parse_nanos = part.is_solved().then_some(part.nanos);
+            } else if label.contains("Part 1") {
+                timings.part_1 = Some(part);
+            } else if label.contains("Part 2") {
+                timings.part_2 = Some(part);
+            }
+        }
 
         timings
     }
 
-    fn parse_to_float(s: &str, postfix: &str) -> Option<f64> {
-        s.split(postfix).next()?.parse().ok()
+    /// Number of threads the solution reported while being benched.
+    pub fn parse_threads(output: &[String]) -> Option<usize> {
+        output.iter().find_map(|line| {
+            let (_, details) = line.rsplit_once('(')?;
+            details
+                .trim_end()
+                .trim_end_matches(')')
+                .split(", ")
+                .find_map(|segment| {
+                    let (count, unit) = segment.split_once(' ')?;
+                    matches!(unit, "thread" | "threads").then(|| count.parse().ok())?
+                })
+        })
+    }
+
+    fn parse_bytes(s: &str) -> Option<u64> {
+        let (value, unit) = s.split_once(' ')?;
+        let value: f64 = value.parse().ok()?;
+
+        let exponent = ["B", "KiB", "MiB", "GiB", "TiB"]
+            .iter()
+            .position(|x| *x == unit)?;
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        Some((value * 1024_f64.powi(exponent as i32)).round() as u64)
     }
 
-    fn parse_time(line: &str) -> Option<(&str, f64)> {
-        // for possible time formats, see: https://github.com/rust-lang/rust/blob/1.64.0/library/core/src/time.rs#L1176-L1200
-        let str_timing = line
-            .split(" samples)")
-            .next()?
-            .split('(')
-            .last()?
-            .split('@')
-            .next()?
-            .trim();
-
-        let parsed_timing = match str_timing {
-            s if s.contains("ns") => s.split("ns").next()?.parse::<f64>().ok(),
-            s if s.contains("µs") => parse_to_float(s, "µs").map(|x| x * 1000_f64),
-            s if s.contains("ms") => parse_to_float(s, "ms").map(|x| x * 1_000_000_f64),
-            s => parse_to_float(s, "s").map(|x| x * 1_000_000_000_f64),
-        }?;
-
-        Some((str_timing, parsed_timing))
+    /// Parses the timing of a line like `Part 1: 42 (74.1ns @ 100 samples, median 70.0ns ±4.0ns, cold 1.2µs, 1.5 KiB, 8 threads)`.
+    /// Statistics are `None` for lines written by older versions of the runner.
+    fn parse_time(line: &str) -> Option<PartTiming> {
+        let (_, details) = line.rsplit_once('(')?;
+        let details = details.trim_end().trim_end_matches(')');
+        let mut segments = details.split(", ");
+
+        let (str_timing, samples) = segments.next()?.split_once('@')?;
+        let mut part = PartTiming::solved(parse_duration(str_timing.trim())?);
+        part.samples = Some(samples.trim().strip_suffix(" samples")?.parse().ok()?);
+
+        let stats = segments.next().and_then(|spread| {
+            let (median, spread) = spread.strip_prefix("median ")?.split_once(" ±")?;
+            Some((parse_duration(median)?, parse_duration(spread)?))
+        });
+
+        if let Some((median, spread)) = stats {
+            part.median_nanos = Some(median);
+            part.spread_nanos = Some(spread);
+
+            for segment in segments {
+                if let Some(base_time) = segment.strip_prefix("cold ") {
+                    part.base_nanos = parse_duration(base_time);
+                } else if !segment.ends_with(" threads") && !segment.ends_with(" thread") {
+                    part.peak_memory = parse_bytes(segment);
+                }
+            }
+        }
+
+        Some(part)
     }
 
     /// copied from: https://github.com/rust-lang/rust/blob/1.64.0/library/std/src/macros.rs#L328-L333
@@ -205,9 +257,10 @@ pub mod child_commands {
 
     #[cfg(feature = "test_lib")]
     mod tests {
-        use super::parse_exec_time;
+        use super::{parse_exec_time, parse_threads};
 
         use crate::day;
+        use crate::template::timings::{PartStatus, PartTiming};
 
         #[test]
         fn parses_execution_times() {
@@ -219,9 +272,9 @@ pub mod child_commands {
                 ],
                 day!(1),
             );
-            assert_approx_eq!(res.total_nanos, 74130074.13_f64);
-            assert_eq!(res.part_1.unwrap(), "74.13ns");
-            assert_eq!(res.part_2.unwrap(), "74.13ms");
+            assert_approx_eq!(res.total_nanos(), 74130074.13_f64);
+            assert_approx_eq!(res.part_nanos(1).unwrap(), 74.13_f64);
+            assert_approx_eq!(res.part_nanos(2).unwrap(), 74130000_f64);
         }
 
         #[test]
@@ -234,9 +287,52 @@ pub mod child_commands {
                 ],
                 day!(1),
             );
-            assert_approx_eq!(res.total_nanos, 2100000000_f64);
-            assert_eq!(res.part_1.unwrap(), "2s");
-            assert_eq!(res.part_2.unwrap(), "100ms");
+            assert_approx_eq!(res.total_nanos(), 2100000000_f64);
+            assert_eq!(res.part_1.unwrap().samples, Some(5));
+            assert_eq!(res.part_2.unwrap().samples, Some(1));
+        }
+
+        #[test]
+        fn parses_statistics() {
+            let output = [
+                "Parse: (1.5µs @ 1000 samples, median 1.0µs ±200.0ns, 3.0 MiB)".into(),
+                "Part 1: 0 (74.13ns @ 100000 samples, median 70.0ns ±4.0ns, cold 1.2µs, 1.5 KiB, 8 threads)".into(),
+                "Part 2: 10 (74.13ms @ 99999 samples, median 70.0ms ±1.0ms)".into(),
+                "".into(),
+            ];
+            let res = parse_exec_time(&output, day!(1));
+            assert_eq!(parse_threads(&output), Some(8));
+            assert_eq!(parse_threads(&output[2..]), None);
+            assert_eq!(
+                parse_threads(&[
+                    "Part 1: 0 (1.0ns @ 10 samples, median 1.0ns ±0.0ns, cold 1.0ns, 1 thread)"
+                        .into()
+                ]),
+                Some(1)
+            );
+            assert_approx_eq!(res.total_nanos(), 74130074.13_f64);
+            assert_eq!(res.parse_nanos, Some(1500_f64));
+            assert_eq!(
+                res.part_1,
+                Some(PartTiming {
+                    samples: Some(100000),
+                    median_nanos: Some(70_f64),
+                    spread_nanos: Some(4_f64),
+                    peak_memory: Some(1536),
+                    base_nanos: Some(1200_f64),
+                    ..PartTiming::solved(74.13)
+                })
+            );
+            assert_eq!(
+                res.part_2,
+                Some(PartTiming {
+                    samples: Some(99999),
+                    median_nanos: Some(70_000_000_f64),
+                    spread_nanos: Some(1_000_000_f64),
+                    peak_memory: None,
+                    ..PartTiming::solved(74_130_000_f64)
+                })
+            );
         }
 
         #[test]
@@ -249,9 +345,9 @@ pub mod child_commands {
                 ],
                 day!(1),
             );
-            assert_approx_eq!(res.total_nanos, 0_f64);
-            assert_eq!(res.part_1.is_none(), true);
-            assert_eq!(res.part_2.is_none(), true);
+            assert_approx_eq!(res.total_nanos(), 0_f64);
+            assert_eq!(res.part_1.unwrap().status, PartStatus::Unsolved);
+            assert_eq!(res.part_2.unwrap().status, PartStatus::Unsolved);
         }
     }
 }
@@ -6,60 +6,320 @@ use std::process::Output;
 use std::time::{Duration, Instant};
 use std::{cmp, env, process};
 
+use crate::template::answers::{Answers, SubmitOutcome};
+use crate::template::commands::read;
+use crate::template::peak_alloc;
 use crate::template::ANSI_BOLD;
 use crate::template::{aoc_cli, Day, ANSI_ITALIC, ANSI_RESET};
 
-pub fn run_part<I: Clone, T: Display>(func: impl Fn(I) -> Option<T>, input: I, day: Day, part: u8) {
+/// Bounds for benching the parts of a solution. Days can set their own with
+/// `solution!(1, bench = BenchConfig::new().bench_time(Duration::from_secs(60)))`,
+/// flags passed to `cargo time` take precedence.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BenchConfig {
+    /// Approximate time spent taking samples of a part.
+    pub bench_time: Duration,
+    /// Time spent running a part before samples are taken.
+    pub warmup: Duration,
+    pub min_samples: u128,
+    pub max_samples: u128,
+}
+
+impl Default for BenchConfig {
+    fn default() -> Self {
+        BenchConfig::new()
+    }
+}
+
+impl BenchConfig {
+    pub const fn new() -> Self {
+        BenchConfig {
+            bench_time: Duration::from_secs(1),
+            warmup: Duration::ZERO,
+            min_samples: 10,
+            max_samples: 10000,
+        }
+    }
+
+    #[must_use]
+    pub const fn bench_time(mut self, bench_time: Duration) -> Self {
+        self.bench_time = bench_time;
+        self
+    }
+
+    #[must_use]
+    pub const fn warmup(mut self, warmup: Duration) -> Self {
+        self.warmup = warmup;
+        self
+    }
+
+    #[must_use]
+    pub const fn min_samples(mut self, min_samples: u128) -> Self {
+        self.min_samples = min_samples;
+        self
+    }
+
+    #[must_use]
+    pub const fn max_samples(mut self, max_samples: u128) -> Self {
+        self.max_samples = max_samples;
+        self
+    }
+
+    /// Applies the bench flags passed to the solution, exiting if they are malformed.
+    pub fn with_args(self) -> Self {
+        let args: Vec<String> = env::args().collect();
+
+        match BenchOverrides::from_args(&args) {
+            Ok(overrides) => self.apply(&overrides),
+            Err(e) => {
+                eprintln!("{e}");
+                process::exit(1);
+            }
+        }
+    }
+
+    #[must_use]
+    pub fn apply(self, overrides: &BenchOverrides) -> Self {
+        BenchConfig {
+            bench_time: overrides.bench_time.unwrap_or(self.bench_time),
+            warmup: overrides.warmup.unwrap_or(self.warmup),
+            min_samples: overrides.min_samples.unwrap_or(self.min_samples),
+            max_samples: overrides.max_samples.unwrap_or(self.max_samples),
+        }
+    }
+
+    /// Number of samples to take of a function that takes `sample_time` per run.
+    fn iterations(&self, sample_time: Duration) -> u128 {
+        let min_samples = self.min_samples.max(1);
+
+        (self.bench_time.as_nanos() / cmp::max(sample_time.as_nanos(), 10))
+            .clamp(min_samples, self.max_samples.max(min_samples))
+    }
+}
+
+/// Bench settings passed on the command line, e.g. `cargo time --bench-time 10 --min-samples 3`.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct BenchOverrides {
+    pub bench_time: Option<Duration>,
+    pub warmup: Option<Duration>,
+    pub min_samples: Option<u128>,
+    pub max_samples: Option<u128>,
+}
+
+impl BenchOverrides {
+    pub fn from_args(args: &[String]) -> Result<Self, String> {
+        let value = |flag: &str| -> Result<Option<&str>, String> {
+            match args.iter().position(|x| x == flag) {
+                None => Ok(None),
+                Some(i) => args
+                    .get(i + 1)
+                    .map(|x| Some(x.as_str()))
+                    .ok_or(format!("Expected a value after {flag}.")),
+            }
+        };
+
+        let seconds = |flag: &str| -> Result<Option<Duration>, String> {
+            value(flag)?
+                .map(|x| parse_seconds(x).map_err(|e| format!("Expected {flag} to be {e}")))
+                .transpose()
+        };
+
+        let count = |flag: &str| -> Result<Option<u128>, String> {
+            value(flag)?
+                .map(|x| {
+                    x.parse::<u128>()
+                        .map_err(|_| format!("Expected {flag} to be a number, got \"{x}\"."))
+                })
+                .transpose()
+        };
+
+        Ok(BenchOverrides {
+            bench_time: seconds("--bench-time")?,
+            warmup: seconds("--warmup")?,
+            min_samples: count("--min-samples")?,
+            max_samples: count("--max-samples")?,
+        })
+    }
+
+    /// Formats the overrides as flags to pass them on to a solution.
+    pub fn to_args(&self) -> Vec<String> {
+        let mut args = vec![];
+
+        let seconds = [("--bench-time", self.bench_time), ("--warmup", self.warmup)];
+        for (flag, value) in seconds {
+            if let Some(value) = value {
+                args.extend([flag.into(), value.as_secs_f64().to_string()]);
+            }
+        }
+
+        let counts = [
+            ("--min-samples", self.min_samples),
+            ("--max-samples", self.max_samples),
+        ];
+        for (flag, value) in counts {
+            if let Some(value) = value {
+                args.extend([flag.into(), value.to_string()]);
+            }
+        }
+
+        args
+    }
+}
+
+/// Parses a non-negative number of seconds, e.g. `0.5`.
+pub fn parse_seconds(s: &str) -> Result<Duration, String> {
+    s.parse::<f64>()
+        .ok()
+        .and_then(|x| Duration::try_from_secs_f64(x).ok())
+        .ok_or(format!("a number of seconds, got \"{s}\"."))
+}
+
+/// Return types of solution parts. `None` marks a part as unsolved,
+/// an `Err` is printed below the part, e.g. a [`ParseError`](crate::parse::ParseError).
+pub trait PartResult {
+    type Answer: Display;
+
+    fn answer(&self) -> Result<Option<&Self::Answer>, String>;
+}
+
+impl<T: Display> PartResult for Option<T> {
+    type Answer = T;
+
+    fn answer(&self) -> Result<Option<&T>, String> {
+        Ok(self.as_ref())
+    }
+}
+
+impl<T: Display, E: Display> PartResult for Result<T, E> {
+    type Answer = T;
+
+    fn answer(&self) -> Result<Option<&T>, String> {
+        self.as_ref().map(Some).map_err(ToString::to_string)
+    }
+}
+
+pub fn run_part<I: Clone, R: PartResult>(
+    func: impl Fn(I) -> R,
+    input: I,
+    day: Day,
+    part: u8,
+    config: &BenchConfig,
+) {
     let part_str = format!("Part {part}");
 
-    let (result, duration, samples) =
-        run_timed(func, input, |result| print_result(result, &part_str, ""));
+    let (result, measurement) = run_timed(func, input, config, |result| {
+        print_result(&result.answer().ok().flatten(), &part_str, "");
+    });
+
+    let answer = result.answer();
+    print_result(
+        &answer.as_ref().ok().copied().flatten(),
+        &part_str,
+        &format_measurement(&measurement),
+    );
+
+    if let Err(e) = &answer {
+        eprintln!("  {e}");
+    }
 
-    print_result(&result, &part_str, &format_duration(&duration, samples));
+    if let Ok(Some(result)) = answer {
+        if let Some(Ok(output)) = submit_result(&result, day, part) {
+            record_submission(&result, day, part, &output);
+        }
+    }
+}
 
-    if let Some(result) = result {
-        submit_result(result, day, part);
+/// Bench the function that parses the input, if the solution declares one. Only runs with `--time`.
+pub fn run_parse<I: Clone, T>(func: impl Fn(I) -> T, input: I, config: &BenchConfig) {
+    if !env::args().any(|x| x == "--time") {
+        return;
     }
+
+    let (_, measurement) = run_timed(func, input, config, |_| print!("Parse: "));
+    println!("\rParse:{}", format_measurement(&measurement));
+}
+
+/// Execution time statistics of a solution part.
+struct Measurement {
+    /// Duration of the first, cold run.
+    base_time: Duration,
+    mean: Duration,
+    median: Duration,
+    spread: Duration,
+    samples: u128,
+    peak_memory: Option<u64>,
 }
 
 /// Run a solution part. The behavior differs depending on whether we are running a release or debug build:
 ///  1. in debug, the function is executed once.
-///  2. in release, the function is benched (approx. 1 second of execution time or 10 samples, whatever take longer.)
+///  2. in release, the function is benched (approx. `config.bench_time` of execution time, within the sample bounds of `config`.)
+///
+/// The peak heap usage is measured during the first execution.
 fn run_timed<I: Clone, T>(
     func: impl Fn(I) -> T,
     input: I,
+    config: &BenchConfig,
     hook: impl Fn(&T),
-) -> (T, Duration, u128) {
+) -> (T, Measurement) {
     let timer = Instant::now();
-    let result = {
+    let (result, peak_memory) = {
         let input = input.clone();
 
         #[cfg(feature = "dhat-heap")]
         let _profiler = dhat::Profiler::new_heap();
 
-        func(input)
+        peak_alloc::measure(|| func(input))
     };
     let base_time = timer.elapsed();
 
     hook(&result);
 
-    let run = if std::env::args().any(|x| x == "--time") {
-        bench(func, input, &base_time)
+    let mut measurement = if std::env::args().any(|x| x == "--time") {
+        bench(func, input, base_time, config)
     } else {
-        (base_time, 1)
+        Measurement {
+            base_time,
+            mean: base_time,
+            median: base_time,
+            spread: Duration::ZERO,
+            samples: 1,
+            peak_memory: None,
+        }
     };
 
-    (result, run.0, run.1)
+    measurement.peak_memory = peak_memory;
+
+    (result, measurement)
 }
 
-fn bench<I: Clone, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) -> (Duration, u128) {
+fn bench<I: Clone, T>(
+    func: impl Fn(I) -> T,
+    input: I,
+    base_time: Duration,
+    config: &BenchConfig,
+) -> Measurement {
     let mut stdout = stdout();
 
     print!(" > {ANSI_ITALIC}benching{ANSI_RESET}");
     let _ = stdout.flush();
 
-    let bench_iterations =
-        (Duration::from_secs(1).as_nanos() / cmp::max(base_time.as_nanos(), 10)).clamp(10, 10000);
+    // estimate the time per run from the warm runs if there are any, the first run is usually slower.
+    let mut sample_time = base_time;
+    let warmup = Instant::now();
+    let mut warmup_runs: u128 = 0;
+
+    while warmup.elapsed() < config.warmup {
+        black_box(func(black_box(input.clone())));
+        warmup_runs += 1;
+    }
+
+    if let Some(nanos) = warmup.elapsed().as_nanos().checked_div(warmup_runs) {
+        #[allow(clippy::cast_possible_truncation)]
+        let nanos = nanos as u64;
+        sample_time = Duration::from_nanos(nanos);
+    }
+
+    let bench_iterations = config.iterations(sample_time);
 
     let mut timers: Vec<Duration> = vec![];
 
@@ -71,11 +331,17 @@ fn bench<I: Clone, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) ->
         timers.push(timer.elapsed());
     }
 
-    (
-        #[allow(clippy::cast_possible_truncation)]
-        Duration::from_nanos(average_duration(&timers) as u64),
-        bench_iterations,
-    )
+    let mean = average_duration(&timers);
+
+    #[allow(clippy::cast_possible_truncation)]
+    Measurement {
+        base_time,
+        mean: Duration::from_nanos(mean as u64),
+        median: median_duration(&mut timers),
+        spread: Duration::from_nanos(standard_deviation(&timers, mean) as u64),
+        samples: bench_iterations,
+        peak_memory: None,
+    }
 }
 
 fn average_duration(numbers: &[Duration]) -> u128 {
@@ -86,11 +352,51 @@ fn average_duration(numbers: &[Duration]) -> u128 {
         / numbers.len() as u128
 }
 
-fn format_duration(duration: &Duration, samples: u128) -> String {
-    if samples == 1 {
-        format!(" ({duration:.1?})")
+fn median_duration(numbers: &mut [Duration]) -> Duration {
+    numbers.sort_unstable();
+    let mid = numbers.len() / 2;
+
+    if numbers.len().is_multiple_of(2) {
+        (numbers[mid - 1] + numbers[mid]) / 2
     } else {
-        format!(" ({duration:.1?} @ {samples} samples)")
+        numbers[mid]
+    }
+}
+
+#[allow(clippy::cast_precision_loss)]
+fn standard_deviation(numbers: &[Duration], mean: u128) -> f64 {
+    let variance = numbers
+        .iter()
+        .map(|x| (x.as_nanos() as f64 - mean as f64).powi(2))
+        .sum::<f64>()
+        / numbers.len() as f64;
+
+    variance.sqrt()
+}
+
+fn format_measurement(measurement: &Measurement) -> String {
+    let Measurement {
+        base_time,
+        mean,
+        median,
+        spread,
+        samples,
+        peak_memory,
+    } = measurement;
+
+    let memory = peak_memory
+        .map(|x| format!(", {}", peak_alloc::format_bytes(x)))
+        .unwrap_or_default();
+
+    if *samples == 1 {
+        format!(" ({mean:.1?})")
+    } else {
+        // the thread count is reported by the solution, since `cargo time` runs in another process.
+        let threads = match rayon::current_num_threads() {
+            1 => "1 thread".into(),
+            n => format!("{n} threads"),
+        };
+        format!(" ({mean:.1?} @ {samples} samples, median {median:.1?} ±{spread:.1?}, cold {base_time:.1?}{memory}, {threads})")
     }
 }
 
@@ -129,11 +435,31 @@ fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str)
     }
 }
 
+/// Store the outcome of a submission, so that progress is known locally.
+/// Once part one is solved, the puzzle is downloaded again to show part two.
+fn record_submission<T: Display>(result: &T, day: Day, part: u8, output: &Output) {
+    let outcome = SubmitOutcome::parse(&String::from_utf8_lossy(&output.stdout));
+    let solved_part_one = part == 1 && outcome == SubmitOutcome::Correct;
+    let mut answers = Answers::read_from_file();
+    answers.record(day, part, &result.to_string(), outcome);
+
+    if let Err(e) = answers.store_file() {
+        eprintln!("Failed to store submission outcome: {e}");
+    }
+
+    if solved_part_one {
+        println!();
+        if let Err(e) = read::show_new(day) {
+            eprintln!("Failed to download part two: {e}");
+        }
+    }
+}
+
 /// Parse the arguments passed to `solve` and try to submit one part of the solution if:
 ///  1. we are in `--release` mode.
 ///  2. aoc-cli is installed.
 fn submit_result<T: Display>(
-    result: T,
+    result: &T,
     day: Day,
     part: u8,
 ) -> Option<Result<Output, aoc_cli::AocCommandError>> {
@@ -167,3 +493,81 @@ fn submit_result<T: Display>(
     println!("Submitting result via aoc-cli...");
     Some(aoc_cli::submit(day, part, &result.to_string()))
 }
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::Duration;
+
+    use super::{BenchConfig, BenchOverrides, PartResult};
+
+    fn to_args(args: &str) -> Vec<String> {
+        args.split(' ').map(String::from).collect()
+    }
+
+    #[test]
+    fn parses_overrides() {
+        let overrides =
+            BenchOverrides::from_args(&to_args("01 --time --bench-time 2.5 --min-samples 3"))
+                .unwrap();
+
+        assert_eq!(
+            overrides,
+            BenchOverrides {
+                bench_time: Some(Duration::from_millis(2500)),
+                min_samples: Some(3),
+                ..BenchOverrides::default()
+            }
+        );
+        assert_eq!(
+            BenchOverrides::from_args(&overrides.to_args()),
+            Ok(overrides)
+        );
+
+        assert!(BenchOverrides::from_args(&to_args("--warmup")).is_err());
+        assert!(BenchOverrides::from_args(&to_args("--warmup -1")).is_err());
+        assert!(BenchOverrides::from_args(&to_args("--max-samples many")).is_err());
+    }
+
+    #[test]
+    fn applies_overrides() {
+        let config = BenchConfig::new()
+            .bench_time(Duration::from_secs(60))
+            .min_samples(2);
+
+        let overrides = BenchOverrides {
+            min_samples: Some(5),
+            ..BenchOverrides::default()
+        };
+
+        assert_eq!(
+            config.apply(&overrides),
+            BenchConfig::new()
+                .bench_time(Duration::from_secs(60))
+                .min_samples(5)
+        );
+    }
+
+    #[test]
+    fn bounds_iterations() {
+        let config = BenchConfig::new();
+        assert_eq!(config.iterations(Duration::from_millis(10)), 100);
+        assert_eq!(config.iterations(Duration::from_secs(1)), 10);
+        assert_eq!(config.iterations(Duration::ZERO), 10000);
+
+        let config = BenchConfig::new().min_samples(0).max_samples(0);
+        assert_eq!(config.iterations(Duration::from_secs(10)), 1);
+    }
+
+    #[test]
+    fn unwraps_part_results() {
+        assert_eq!(Some(42).answer(), Ok(Some(&42)));
+        assert_eq!(None::<u32>.answer(), Ok(None));
+        assert_eq!(Ok::<u32, String>(7).answer(), Ok(Some(&7)));
+        assert_eq!(
+            Err::<u32, _>("line 1: expected u32").answer(),
+            Err("line 1: expected u32".into())
+        );
+    }
+}
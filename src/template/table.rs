@@ -0,0 +1,34 @@
+/// Renders `rows` below `header`, with columns padded to their widest cell.
+pub fn render_table(header: &[String], rows: Vec<Vec<String>>) -> String {
+    let widths: Vec<usize> = header
+        .iter()
+        .enumerate()
+        .map(|(i, title)| {
+            rows.iter()
+                .map(|row| row[i].chars().count())
+                .chain([title.chars().count()])
+                .max()
+                .unwrap_or(0)
+        })
+        .collect();
+
+    let format_row = |row: &[String]| {
+        row.iter()
+            .zip(&widths)
+            .map(|(cell, width)| format!("{cell}{}", " ".repeat(width - cell.chars().count())))
+            .collect::<Vec<_>>()
+            .join(" │ ")
+            .trim_end()
+            .to_string()
+    };
+
+    let separator = widths
+        .iter()
+        .map(|width| "─".repeat(*width))
+        .collect::<Vec<_>>()
+        .join("─┼─");
+
+    let mut lines = vec![format_row(header), separator];
+    lines.extend(rows.iter().map(|row| format_row(row)));
+    lines.join("\n")
+}
@@ -1,27 +1,210 @@
-use std::{collections::HashMap, fs, io::Error, str::FromStr};
+use std::{
+    collections::HashMap,
+    fs,
+    io::Error,
+    str::FromStr,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
 use tinyjson::JsonValue;
 
+use crate::template::host::{local_profile_name, Host};
 use crate::template::Day;
 
 static TIMINGS_FILE_PATH: &str = "./data/timings.json";
 
-/// Represents benchmark times for a single day.
-#[derive(Clone, Debug)]
+/// Version of the `timings.json` schema written by this template.
+/// Files without a version are from before the schema was versioned and are migrated when read.
+/// Version 2 stored the timings of a single machine, which are migrated to the profile of this machine.
+const SCHEMA_VERSION: u8 = 3;
+
+pub fn get_file_path() -> &'static str {
+    TIMINGS_FILE_PATH
+}
+
+/// Whether a part returned a result when it was benched.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PartStatus {
+    Solved,
+    Unsolved,
+}
+
+/// Represents benchmark results of a single part.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PartTiming {
+    pub status: PartStatus,
+    /// Mean execution time.
+    pub nanos: f64,
+    /// `None` for timings migrated from the unversioned format.
+    pub samples: Option<u64>,
+    pub median_nanos: Option<f64>,
+    /// Standard deviation of the samples.
+    pub spread_nanos: Option<f64>,
+    /// Peak heap usage in bytes.
+    pub peak_memory: Option<u64>,
+    /// Duration of the first, cold run.
+    pub base_nanos: Option<f64>,
+    /// `None` for timings stored before provenance was recorded.
+    pub provenance: Option<Provenance>,
+}
+
+/// Records when and how a part was measured.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Provenance {
+    /// Seconds since the unix epoch.
+    pub measured_at: u64,
+    pub is_release: bool,
+    /// Number of threads available to the solution, e.g. to `rayon`.
+    pub threads: usize,
+}
+
+impl Provenance {
+    /// Describes a measurement taken now, with the number of `threads` the solution reported.
+    pub fn current(is_release: bool, threads: usize) -> Self {
+        Provenance {
+            measured_at: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map_or(0, |x| x.as_secs()),
+            is_release,
+            threads,
+        }
+    }
+}
+
+impl PartTiming {
+    pub fn solved(nanos: f64) -> Self {
+        PartTiming {
+            status: PartStatus::Solved,
+            nanos,
+            samples: None,
+            median_nanos: None,
+            spread_nanos: None,
+            peak_memory: None,
+            base_nanos: None,
+            provenance: None,
+        }
+    }
+
+    pub fn unsolved() -> Self {
+        PartTiming {
+            status: PartStatus::Unsolved,
+            ..PartTiming::solved(0.0)
+        }
+    }
+
+    pub fn is_solved(&self) -> bool {
+        self.status == PartStatus::Solved
+    }
+
+    /// Whether this is a solved part measured with an optimized build.
+    /// Parts without provenance were stored by `cargo time`, which always benches release builds.
+    pub fn is_complete(&self) -> bool {
+        self.is_solved() && self.provenance.is_none_or(|x| x.is_release)
+    }
+}
+
+/// Represents benchmark times for a single day. Parts that were not benched are `None`.
+#[derive(Clone, Debug, PartialEq)]
 pub struct Timing {
     pub day: Day,
-    pub part_1: Option<String>,
-    pub part_2: Option<String>,
-    pub total_nanos: f64,
+    pub part_1: Option<PartTiming>,
+    pub part_2: Option<PartTiming>,
+    pub parse_nanos: Option<f64>,
 }
 
-/// Represents benchmark times for a set of days.
+impl Timing {
+    pub fn new(day: Day) -> Self {
+        Timing {
+            day,
+            part_1: None,
+            part_2: None,
+            parse_nanos: None,
+        }
+    }
+
+    /// Attaches `provenance` to all benched parts.
+    pub fn set_provenance(&mut self, provenance: Provenance) {
+        for part in [&mut self.part_1, &mut self.part_2].into_iter().flatten() {
+            part.provenance = Some(provenance);
+        }
+    }
+
+    /// Merges a newer timing of the same day into this one, part by part.
+    /// A part is only replaced if the newer run solved it, or if it was not solved before,
+    /// so that a run which did not produce a timing for a part keeps the stored one.
+    fn merge(&mut self, new: &Timing) {
+        for (part, new_part) in [
+            (&mut self.part_1, &new.part_1),
+            (&mut self.part_2, &new.part_2),
+        ] {
+            let Some(new_part) = new_part else {
+                continue;
+            };
+
+            if new_part.is_solved() || !part.as_ref().is_some_and(PartTiming::is_solved) {
+                *part = Some(new_part.clone());
+            }
+        }
+
+        if new.parse_nanos.is_some() {
+            self.parse_nanos = new.parse_nanos;
+        }
+    }
+
+    pub fn get_part(&self, part: u8) -> Option<&PartTiming> {
+        match part {
+            1 => self.part_1.as_ref(),
+            2 => self.part_2.as_ref(),
+            _ => None,
+        }
+    }
+
+    /// Mean time of a solved part in nanoseconds.
+    pub fn part_nanos(&self, part: u8) -> Option<f64> {
+        self.get_part(part)
+            .filter(|x| x.is_solved())
+            .map(|x| x.nanos)
+    }
+
+    /// Formats the mean time of a part, `✖` if it is unsolved and `-` if it was not benched.
+    pub fn format_part(&self, part: u8) -> String {
+        match self.get_part(part) {
+            Some(x) if x.is_solved() => format_nanos(x.nanos),
+            Some(_) => "✖".into(),
+            None => "-".into(),
+        }
+    }
+
+    /// Sum of the mean times of the solved parts.
+    pub fn total_nanos(&self) -> f64 {
+        (1..=2).filter_map(|part| self.part_nanos(part)).sum()
+    }
+
+    /// Peak heap usage of the part that used the most memory.
+    pub fn peak_memory(&self) -> Option<u64> {
+        [&self.part_1, &self.part_2]
+            .into_iter()
+            .filter_map(|x| x.as_ref()?.peak_memory)
+            .max()
+    }
+}
+
+/// Timings of a single machine, tagged with the host they were measured on.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Profile {
+    pub name: String,
+    /// `None` for timings stored before profiles existed.
+    pub host: Option<Host>,
+    pub timings: Timings,
+}
+
+/// Represents the timings of all machines, stored side by side.
 /// Can be serialized from / to JSON.
 #[derive(Clone, Debug, Default)]
-pub struct Timings {
-    pub data: Vec<Timing>,
+pub struct TimingProfiles {
+    pub profiles: Vec<Profile>,
 }
 
-impl Timings {
+impl TimingProfiles {
     /// Dehydrate timings to a JSON file.
     pub fn store_file(&self) -> Result<(), Error> {
         let json = JsonValue::from(self.clone());
@@ -30,31 +213,71 @@ impl Timings {
     }
 
     /// Rehydrate timings from a JSON file. If not present, returns empty timings.
-    pub fn read_from_file() -> Self {
-        let s = fs::read_to_string(TIMINGS_FILE_PATH)
-            .map_err(|x| x.to_string())
-            .and_then(Timings::try_from);
+    pub fn try_read_from_file() -> Result<Self, String> {
+        match fs::read_to_string(TIMINGS_FILE_PATH) {
+            Ok(s) => TimingProfiles::try_from(s),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(TimingProfiles::default()),
+            Err(e) => Err(e.to_string()),
+        }
+    }
 
-        match s {
-            Ok(timings) => timings,
+    /// Rehydrate timings from a JSON file. If not present or invalid, returns empty timings.
+    pub fn read_from_file() -> Self {
+        match TimingProfiles::try_read_from_file() {
+            Ok(profiles) => profiles,
             Err(e) => {
                 eprintln!("{e}");
-                Timings::default()
+                TimingProfiles::default()
             }
         }
     }
 
-    /// Merge two sets of timings, overwriting `self` with `other` if present.
-    pub fn merge(&self, new: &Self) -> Self {
-        let mut data: Vec<Timing> = vec![];
+    pub fn get(&self, name: &str) -> Option<&Profile> {
+        self.profiles.iter().find(|x| x.name == name)
+    }
 
-        for timing in &new.data {
-            data.push(timing.clone());
+    /// Returns the profile `name`, or the profile of this machine if no name is given.
+    /// Falls back to the first profile if this machine has not stored any timings yet.
+    pub fn select(&self, name: Option<&str>) -> Option<&Profile> {
+        match name {
+            Some(name) => self.get(name),
+            None => self
+                .get(&local_profile_name())
+                .or_else(|| self.profiles.first()),
         }
+    }
+
+    /// Merges `timings` into the profile `name`, creating it if needed, and tags it with `host`.
+    pub fn store(&mut self, name: &str, host: Host, timings: &Timings) {
+        match self.profiles.iter_mut().find(|x| x.name == name) {
+            Some(profile) => {
+                profile.host = Some(host);
+                profile.timings = profile.timings.merge(timings);
+            }
+            None => self.profiles.push(Profile {
+                name: name.into(),
+                host: Some(host),
+                timings: Timings::default().merge(timings),
+            }),
+        }
+    }
+}
+
+/// Represents benchmark times for a set of days.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Timings {
+    pub data: Vec<Timing>,
+}
 
-        for timing in &self.data {
-            if !data.iter().any(|t| t.day == timing.day) {
-                data.push(timing.clone());
+impl Timings {
+    /// Merge two sets of timings, overwriting the parts of `self` with those of `new` if present.
+    pub fn merge(&self, new: &Self) -> Self {
+        let mut data: Vec<Timing> = self.data.clone();
+
+        for timing in &new.data {
+            match data.iter_mut().find(|t| t.day == timing.day) {
+                Some(stored) => stored.merge(timing),
+                None => data.push(timing.clone()),
             }
         }
 
@@ -64,80 +287,193 @@ impl Timings {
 
     /// Sum up total duration of timings as millis.
     pub fn total_millis(&self) -> f64 {
-        self.data.iter().map(|x| x.total_nanos).sum::<f64>() / 1_000_000_f64
+        self.data.iter().map(Timing::total_nanos).sum::<f64>() / 1_000_000_f64
     }
 
     pub fn is_day_complete(&self, day: Day) -> bool {
-        self.data
-            .iter()
-            .any(|t| t.day == day && t.part_1.is_some() && t.part_2.is_some())
+        self.data.iter().any(|t| {
+            t.day == day
+                && t.part_1.as_ref().is_some_and(PartTiming::is_complete)
+                && t.part_2.as_ref().is_some_and(PartTiming::is_complete)
+        })
     }
 }
 
+/// Formats nanoseconds like the runner formats durations, e.g. `74.1µs`.
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+pub fn format_nanos(nanos: f64) -> String {
+    format!("{:.1?}", Duration::from_nanos(nanos as u64))
+}
+
+/// Parses a duration formatted by the runner, e.g. `74.1µs`, to nanoseconds.
+pub fn parse_duration(s: &str) -> Option<f64> {
+    // for possible time formats, see: https://github.com/rust-lang/rust/blob/1.64.0/library/core/src/time.rs#L1176-L1200
+    match s {
+        s if s.contains("ns") => s.split("ns").next()?.parse::<f64>().ok(),
+        s if s.contains("µs") => parse_to_float(s, "µs").map(|x| x * 1000_f64),
+        s if s.contains("ms") => parse_to_float(s, "ms").map(|x| x * 1_000_000_f64),
+        s => parse_to_float(s, "s").map(|x| x * 1_000_000_000_f64),
+    }
+}
+
+fn parse_to_float(s: &str, postfix: &str) -> Option<f64> {
+    s.split(postfix).next()?.parse().ok()
+}
+
 /* -------------------------------------------------------------------------- */
 
-impl From<Timings> for JsonValue {
-    fn from(value: Timings) -> Self {
+impl From<TimingProfiles> for JsonValue {
+    fn from(value: TimingProfiles) -> Self {
         let mut map: HashMap<String, JsonValue> = HashMap::new();
 
         map.insert(
-            "data".into(),
-            JsonValue::Array(value.data.iter().map(JsonValue::from).collect()),
+            "version".into(),
+            JsonValue::Number(f64::from(SCHEMA_VERSION)),
+        );
+
+        map.insert(
+            "profiles".into(),
+            JsonValue::Array(value.profiles.iter().map(JsonValue::from).collect()),
         );
 
         JsonValue::Object(map)
     }
 }
 
-impl TryFrom<String> for Timings {
+impl TryFrom<String> for TimingProfiles {
     type Error = String;
 
     fn try_from(value: String) -> Result<Self, Self::Error> {
         let json = JsonValue::from_str(&value).or(Err("not valid JSON file."))?;
 
-        let json_data = json
+        let json = json
             .get::<HashMap<String, JsonValue>>()
-            .ok_or("expected JSON document to be an object.")?
-            .get("data")
-            .ok_or("expected JSON document to have key `data`.")?
+            .ok_or("expected JSON document to be an object.")?;
+
+        let version = match json.get("version") {
+            None => 1.0,
+            Some(v) => *v
+                .get::<f64>()
+                .ok_or("expected `json.version` to be a number.")?,
+        };
+
+        #[allow(clippy::float_cmp)]
+        let parse_timing: fn(&JsonValue) -> Result<Timing, String> = if version == 1.0 {
+            migrate_unversioned_timing
+        } else if version == 2.0 || version == f64::from(SCHEMA_VERSION) {
+            |x| Timing::try_from(x)
+        } else {
+            return Err(format!(
+                "timings have schema version {version}, but this template only reads versions up to {SCHEMA_VERSION}. Update the template to read them."
+            ));
+        };
+
+        #[allow(clippy::float_cmp)]
+        if version != f64::from(SCHEMA_VERSION) {
+            return Ok(TimingProfiles {
+                profiles: vec![Profile {
+                    name: local_profile_name(),
+                    host: None,
+                    timings: parse_data(json, "json", parse_timing)?,
+                }],
+            });
+        }
+
+        let json_profiles = json
+            .get("profiles")
+            .ok_or("expected JSON document to have key `profiles`.")?
             .get::<Vec<JsonValue>>()
-            .ok_or("expected `json.data` to be an array.")?;
+            .ok_or("expected `json.profiles` to be an array.")?;
 
-        Ok(Timings {
-            data: json_data
+        Ok(TimingProfiles {
+            profiles: json_profiles
                 .iter()
-                .map(Timing::try_from)
+                .map(Profile::try_from)
                 .collect::<Result<_, _>>()?,
         })
     }
 }
 
-/* -------------------------------------------------------------------------- */
+/// Reads the `data` array of an object, which is the document itself for files written before profiles existed.
+fn parse_data(
+    json: &HashMap<String, JsonValue>,
+    path: &str,
+    parse_timing: fn(&JsonValue) -> Result<Timing, String>,
+) -> Result<Timings, String> {
+    let json_data = json
+        .get("data")
+        .ok_or(format!("expected `{path}` to have key `data`."))?
+        .get::<Vec<JsonValue>>()
+        .ok_or(format!("expected `{path}.data` to be an array."))?;
 
-impl From<&Timing> for JsonValue {
-    fn from(value: &Timing) -> Self {
-        let mut map: HashMap<String, JsonValue> = HashMap::new();
+    Ok(Timings {
+        data: json_data
+            .iter()
+            .map(parse_timing)
+            .collect::<Result<_, _>>()?,
+    })
+}
 
-        map.insert("day".into(), JsonValue::String(value.day.to_string()));
-        map.insert("total_nanos".into(), JsonValue::Number(value.total_nanos));
+/* -------------------------------------------------------------------------- */
 
-        let part_1 = value.part_1.clone().map(JsonValue::String);
-        let part_2 = value.part_2.clone().map(JsonValue::String);
+impl From<&Profile> for JsonValue {
+    fn from(value: &Profile) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
 
+        map.insert("name".into(), JsonValue::String(value.name.clone()));
         map.insert(
-            "part_1".into(),
-            match part_1 {
-                Some(x) => x,
-                None => JsonValue::Null,
-            },
+            "host".into(),
+            value.host.as_ref().map_or(JsonValue::Null, JsonValue::from),
         );
+        map.insert(
+            "data".into(),
+            JsonValue::Array(value.timings.data.iter().map(JsonValue::from).collect()),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for Profile {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected profile to be a JSON object.")?;
+
+        let name = json
+            .get("name")
+            .and_then(|v| v.get::<String>())
+            .ok_or("Expected profile.name to be a string.")?;
 
+        let host = match json.get("host") {
+            None | Some(JsonValue::Null) => None,
+            Some(v) => Some(Host::try_from(v)?),
+        };
+
+        Ok(Profile {
+            name: name.clone(),
+            host,
+            timings: parse_data(json, "profile", |x| Timing::try_from(x))?,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&Timing> for JsonValue {
+    fn from(value: &Timing) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        let part = |x: &Option<PartTiming>| x.as_ref().map_or(JsonValue::Null, JsonValue::from);
+
+        map.insert("day".into(), JsonValue::String(value.day.to_string()));
+        map.insert("part_1".into(), part(&value.part_1));
+        map.insert("part_2".into(), part(&value.part_2));
         map.insert(
-            "part_2".into(),
-            match part_2 {
-                Some(x) => x,
-                None => JsonValue::Null,
-            },
+            "parse_nanos".into(),
+            value.parse_nanos.map_or(JsonValue::Null, JsonValue::Number),
         );
 
         JsonValue::Object(map)
@@ -158,190 +494,505 @@ impl TryFrom<&JsonValue> for Timing {
             .and_then(|day| Day::from_str(day).ok())
             .ok_or("Expected timing.day to be a Day struct.")?;
 
-        let part_1 = json
-            .get("part_1")
-            .map(|v| if v.is_null() { None } else { v.get::<String>() })
-            .ok_or("Expected timing.part_1 to be null or string.")?;
+        let get_part = |key: &str| match json.get(key) {
+            Some(JsonValue::Null) => Ok(None),
+            Some(v) => PartTiming::try_from(v).map(Some),
+            None => Err(format!("Expected timing.{key} to be null or an object.")),
+        };
 
-        let part_2 = json
-            .get("part_2")
-            .map(|v| if v.is_null() { None } else { v.get::<String>() })
-            .ok_or("Expected timing.part_2 to be null or string.")?;
+        Ok(Timing {
+            day,
+            part_1: get_part("part_1")?,
+            part_2: get_part("part_2")?,
+            parse_nanos: get_optional_number(json, "timing.parse_nanos")?,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&PartTiming> for JsonValue {
+    fn from(value: &PartTiming) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        let status = match value.status {
+            PartStatus::Solved => "solved",
+            PartStatus::Unsolved => "unsolved",
+        };
+
+        #[allow(clippy::cast_precision_loss)]
+        let optional_integer =
+            |x: Option<u64>| x.map_or(JsonValue::Null, |x| JsonValue::Number(x as f64));
+        let optional_number = |x: Option<f64>| x.map_or(JsonValue::Null, JsonValue::Number);
+
+        map.insert("status".into(), JsonValue::String(status.into()));
+        map.insert("nanos".into(), JsonValue::Number(value.nanos));
+        map.insert("samples".into(), optional_integer(value.samples));
+        map.insert("median_nanos".into(), optional_number(value.median_nanos));
+        map.insert("spread_nanos".into(), optional_number(value.spread_nanos));
+        map.insert("peak_memory".into(), optional_integer(value.peak_memory));
+        map.insert("base_nanos".into(), optional_number(value.base_nanos));
+        map.insert(
+            "provenance".into(),
+            value.provenance.map_or(JsonValue::Null, JsonValue::from),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for PartTiming {
+    type Error = String;
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected part timing to be a JSON object.")?;
+
+        let status = match json
+            .get("status")
+            .and_then(|v| v.get::<String>())
+            .map(String::as_str)
+        {
+            Some("solved") => PartStatus::Solved,
+            Some("unsolved") => PartStatus::Unsolved,
+            _ => return Err("Expected part.status to be \"solved\" or \"unsolved\".".into()),
+        };
 
-        let total_nanos = json
-            .get("total_nanos")
+        let nanos = json
+            .get("nanos")
             .and_then(|v| v.get::<f64>().copied())
-            .ok_or("Expected timing.total_nanos to be a number.")?;
+            .ok_or("Expected part.nanos to be a number.")?;
 
-        Ok(Timing {
-            day,
-            part_1: part_1.cloned(),
-            part_2: part_2.cloned(),
-            total_nanos,
+        Ok(PartTiming {
+            status,
+            nanos,
+            samples: get_optional_number(json, "part.samples")?.map(|x| x as u64),
+            median_nanos: get_optional_number(json, "part.median_nanos")?,
+            spread_nanos: get_optional_number(json, "part.spread_nanos")?,
+            peak_memory: get_optional_number(json, "part.peak_memory")?.map(|x| x as u64),
+            base_nanos: get_optional_number(json, "part.base_nanos")?,
+            provenance: match json.get("provenance") {
+                None | Some(JsonValue::Null) => None,
+                Some(v) => Some(Provenance::try_from(v)?),
+            },
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<Provenance> for JsonValue {
+    #[allow(clippy::cast_precision_loss)]
+    fn from(value: Provenance) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        let build = if value.is_release { "release" } else { "debug" };
+
+        map.insert(
+            "measured_at".into(),
+            JsonValue::Number(value.measured_at as f64),
+        );
+        map.insert("build".into(), JsonValue::String(build.into()));
+        map.insert("threads".into(), JsonValue::Number(value.threads as f64));
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for Provenance {
+    type Error = String;
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected part.provenance to be a JSON object.")?;
+
+        let measured_at = json
+            .get("measured_at")
+            .and_then(|v| v.get::<f64>())
+            .ok_or("Expected provenance.measured_at to be a number.")?;
+
+        let is_release = match json
+            .get("build")
+            .and_then(|v| v.get::<String>())
+            .map(String::as_str)
+        {
+            Some("release") => true,
+            Some("debug") => false,
+            _ => return Err("Expected provenance.build to be \"release\" or \"debug\".".into()),
+        };
+
+        let threads = json
+            .get("threads")
+            .and_then(|v| v.get::<f64>())
+            .ok_or("Expected provenance.threads to be a number.")?;
+
+        Ok(Provenance {
+            measured_at: *measured_at as u64,
+            is_release,
+            threads: *threads as usize,
         })
     }
 }
 
+/// Reads `key` (given as `object.key` for error messages), which may be missing or null.
+fn get_optional_number(
+    json: &HashMap<String, JsonValue>,
+    path: &str,
+) -> Result<Option<f64>, String> {
+    let key = path.rsplit('.').next().unwrap_or(path);
+
+    match json.get(key) {
+        None | Some(JsonValue::Null) => Ok(None),
+        Some(v) => v
+            .get::<f64>()
+            .copied()
+            .map(Some)
+            .ok_or(format!("Expected {path} to be null or a number.")),
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Reads a timing of the unversioned format, which stored parts as formatted durations like `"74.13ns"`.
+/// Detailed statistics were optional in that format.
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+fn migrate_unversioned_timing(value: &JsonValue) -> Result<Timing, String> {
+    let json = value
+        .get::<HashMap<String, JsonValue>>()
+        .ok_or("Expected timing to be a JSON object.")?;
+
+    let day = json
+        .get("day")
+        .and_then(|v| v.get::<String>())
+        .and_then(|day| Day::from_str(day).ok())
+        .ok_or("Expected timing.day to be a Day struct.")?;
+
+    json.get("total_nanos")
+        .and_then(|v| v.get::<f64>())
+        .ok_or("Expected timing.total_nanos to be a number.")?;
+
+    let get_part = |key: &str| -> Result<Option<PartTiming>, String> {
+        let formatted = json
+            .get(key)
+            .map(|v| if v.is_null() { None } else { v.get::<String>() })
+            .ok_or(format!("Expected timing.{key} to be null or string."))?;
+
+        let Some(formatted) = formatted else {
+            return Ok(None);
+        };
+
+        let nanos =
+            parse_duration(formatted).ok_or(format!("Expected timing.{key} to be a duration."))?;
+
+        let stats = match json.get(&format!("{key}_stats")) {
+            None | Some(JsonValue::Null) => HashMap::new(),
+            Some(v) => v
+                .get::<HashMap<String, JsonValue>>()
+                .cloned()
+                .ok_or(format!("Expected timing.{key}_stats to be an object."))?,
+        };
+
+        Ok(Some(PartTiming {
+            status: PartStatus::Solved,
+            nanos,
+            samples: get_optional_number(&stats, "stats.samples")?.map(|x| x as u64),
+            median_nanos: get_optional_number(&stats, "stats.median_nanos")?,
+            spread_nanos: get_optional_number(&stats, "stats.spread_nanos")?,
+            peak_memory: get_optional_number(&stats, "stats.peak_memory")?.map(|x| x as u64),
+            base_nanos: None,
+            provenance: None,
+        }))
+    };
+
+    Ok(Timing {
+        day,
+        part_1: get_part("part_1")?,
+        part_2: get_part("part_2")?,
+        parse_nanos: get_optional_number(json, "timing.parse_nanos")?,
+    })
+}
+
 /* -------------------------------------------------------------------------- */
 
 #[cfg(feature = "test_lib")]
 mod tests {
     use crate::day;
 
-    use super::{Timing, Timings};
+    use super::{PartTiming, Timing, Timings};
+
+    pub fn get_timing(day: u8, part_1: Option<f64>, part_2: Option<f64>) -> Timing {
+        Timing {
+            part_1: part_1.map(PartTiming::solved),
+            part_2: part_2.map(PartTiming::solved),
+            ..Timing::new(crate::template::Day::new(day).unwrap())
+        }
+    }
 
     fn get_mock_timings() -> Timings {
         Timings {
             data: vec![
-                Timing {
-                    day: day!(1),
-                    part_1: Some("10ms".into()),
-                    part_2: Some("20ms".into()),
-                    total_nanos: 3e+10,
-                },
-                Timing {
-                    day: day!(2),
-                    part_1: Some("30ms".into()),
-                    part_2: Some("40ms".into()),
-                    total_nanos: 7e+10,
-                },
-                Timing {
-                    day: day!(4),
-                    part_1: Some("40ms".into()),
-                    part_2: None,
-                    total_nanos: 4e+10,
-                },
+                get_timing(1, Some(1e+10), Some(2e+10)),
+                get_timing(2, Some(3e+10), Some(4e+10)),
+                get_timing(4, Some(4e+10), None),
             ],
         }
     }
 
+    #[test]
+    fn sums_solved_parts() {
+        let mut timing = get_timing(1, Some(10.0), Some(20.0));
+        assert_eq!(timing.total_nanos(), 30.0);
+        timing.part_2 = Some(PartTiming::unsolved());
+        assert_eq!(timing.total_nanos(), 10.0);
+        assert_eq!(timing.format_part(1), "10.0ns");
+        assert_eq!(timing.format_part(2), "✖");
+        assert_eq!(Timing::new(day!(1)).format_part(1), "-");
+        assert_eq!(get_mock_timings().total_millis(), 140_000.0);
+    }
+
     mod deserialization {
-        use crate::{day, template::timings::Timings};
+        use crate::{
+            day,
+            template::host::local_profile_name,
+            template::timings::{PartStatus, PartTiming, TimingProfiles, Timings},
+        };
+
+        /// Parses a document and returns the timings of its first profile.
+        fn parse(json: &str) -> Timings {
+            let profiles = TimingProfiles::try_from(json.to_string()).unwrap();
+            profiles.profiles.into_iter().next().unwrap().timings
+        }
+
+        #[test]
+        fn handles_json_profiles() {
+            let json = r#"{ "version": 3, "profiles": [{ "name": "laptop", "host": { "cpu": "Apple M1", "cores": 8, "os": "macos aarch64", "rustc": "rustc 1.83.0" }, "data": [{ "day": "01", "part_1": null, "part_2": null }] }, { "name": "ci", "host": null, "data": [] }] }"#.to_string();
+            let profiles = TimingProfiles::try_from(json).unwrap();
+            assert_eq!(profiles.profiles.len(), 2);
+            assert_eq!(
+                profiles.get("laptop").unwrap().host.as_ref().unwrap().cores,
+                8
+            );
+            assert_eq!(profiles.get("laptop").unwrap().timings.data.len(), 1);
+            assert_eq!(profiles.select(Some("ci")).unwrap().host, None);
+            assert_eq!(profiles.select(Some("desktop")), None);
+        }
 
         #[test]
-        fn handles_json_timings() {
-            let json = r#"{ "data": [{ "day": "01", "part_1": "1ms", "part_2": null, "total_nanos": 1000000000 }] }"#.to_string();
-            let timings = Timings::try_from(json).unwrap();
+        fn migrates_single_machine_timings() {
+            let json = r#"{ "version": 2, "data": [{ "day": "01", "part_1": { "status": "solved", "nanos": 1000000, "samples": 10, "median_nanos": null }, "part_2": { "status": "unsolved", "nanos": 0 }, "parse_nanos": 5 }] }"#.to_string();
+            let profiles = TimingProfiles::try_from(json).unwrap();
+            assert_eq!(profiles.profiles.len(), 1);
+            assert_eq!(profiles.profiles[0].name, local_profile_name());
+            let timings = &profiles.profiles[0].timings;
             assert_eq!(timings.data.len(), 1);
             let timing = timings.data.first().unwrap();
             assert_eq!(timing.day, day!(1));
-            assert_eq!(timing.part_1, Some("1ms".to_string()));
+            assert_eq!(
+                timing.part_1,
+                Some(PartTiming {
+                    samples: Some(10),
+                    ..PartTiming::solved(1_000_000_f64)
+                })
+            );
+            assert_eq!(timing.part_2.as_ref().unwrap().status, PartStatus::Unsolved);
+            assert_eq!(timing.parse_nanos, Some(5_f64));
+        }
+
+        #[test]
+        fn migrates_unversioned_timings() {
+            let timings = parse(
+                r#"{ "data": [{ "day": "01", "part_1": "1ms", "part_2": null, "total_nanos": 1000000, "part_1_stats": { "median_nanos": 900000, "spread_nanos": 10, "samples": 100, "peak_memory": 64 } }] }"#,
+            );
+            let timing = timings.data.first().unwrap();
+            assert_eq!(timing.day, day!(1));
+            assert_eq!(
+                timing.part_1,
+                Some(PartTiming {
+                    samples: Some(100),
+                    median_nanos: Some(900_000_f64),
+                    spread_nanos: Some(10_f64),
+                    peak_memory: Some(64),
+                    ..PartTiming::solved(1_000_000_f64)
+                })
+            );
             assert_eq!(timing.part_2, None);
-            assert_eq!(timing.total_nanos, 1_000_000_000_f64);
+            assert_eq!(timing.total_nanos(), 1_000_000_f64);
         }
 
         #[test]
         fn handles_empty_timings() {
-            let json = r#"{ "data": [] }"#.to_string();
-            let timings = Timings::try_from(json).unwrap();
+            let timings = parse(r#"{ "data": [] }"#);
             assert_eq!(timings.data.len(), 0);
         }
 
+        #[test]
+        fn rejects_unknown_versions() {
+            let json = r#"{ "version": 4, "profiles": [] }"#.to_string();
+            let error = TimingProfiles::try_from(json).unwrap_err();
+            assert!(error.contains("schema version 4"));
+        }
+
         #[test]
         #[should_panic]
         fn panics_for_invalid_json() {
-            let json = r#"{}"#.to_string();
-            Timings::try_from(json).unwrap();
+            parse(r#"{}"#);
         }
 
         #[test]
         #[should_panic]
         fn panics_for_malformed_timings() {
-            let json = r#"{ "data": [{ "day": "01" }, { "day": "26" }, { "day": "02", "part_2": null, "total_nanos": 0 }] }"#.to_string();
-            Timings::try_from(json).unwrap();
+            parse(
+                r#"{ "data": [{ "day": "01" }, { "day": "26" }, { "day": "02", "part_2": null, "total_nanos": 0 }] }"#,
+            );
         }
     }
 
     mod serialization {
         use super::get_mock_timings;
+        use crate::template::host::Host;
+        use crate::template::timings::{Profile, Provenance, TimingProfiles, Timings};
         use std::collections::HashMap;
         use tinyjson::JsonValue;
 
+        fn get_mock_profiles() -> TimingProfiles {
+            let mut timings = get_mock_timings();
+            timings.data[0].set_provenance(Provenance {
+                measured_at: 1_733_011_200,
+                is_release: true,
+                threads: 8,
+            });
+
+            TimingProfiles {
+                profiles: vec![
+                    Profile {
+                        name: "laptop".into(),
+                        host: Some(Host {
+                            cpu: "Apple M1".into(),
+                            cores: 8,
+                            os: "macos aarch64".into(),
+                            rustc: "rustc 1.83.0".into(),
+                        }),
+                        timings,
+                    },
+                    Profile {
+                        name: "default".into(),
+                        host: None,
+                        timings: Timings::default(),
+                    },
+                ],
+            }
+        }
+
         #[test]
         fn serializes_timings() {
-            let timings = get_mock_timings();
-            let value = JsonValue::try_from(timings).unwrap();
+            let value = JsonValue::from(get_mock_profiles());
+            let json = value.get::<HashMap<String, JsonValue>>().unwrap();
+            assert_eq!(json.get("version"), Some(&JsonValue::Number(3.0)));
             assert_eq!(
-                value
-                    .get::<HashMap<String, JsonValue>>()
-                    .unwrap()
-                    .get("data")
+                json.get("profiles")
                     .unwrap()
                     .get::<Vec<JsonValue>>()
                     .unwrap()
                     .len(),
-                3
+                2
             );
         }
+
+        #[test]
+        fn roundtrips_timings() {
+            let profiles = get_mock_profiles();
+            let json = JsonValue::from(profiles.clone()).stringify().unwrap();
+            let parsed = TimingProfiles::try_from(json).unwrap();
+            assert_eq!(parsed.profiles, profiles.profiles);
+        }
+
+        #[test]
+        fn stores_to_profiles() {
+            let mut profiles = get_mock_profiles();
+            let host = profiles.profiles[0].host.clone().unwrap();
+
+            profiles.store("laptop", host.clone(), &get_mock_timings());
+            profiles.store("desktop", host, &get_mock_timings());
+
+            assert_eq!(profiles.profiles.len(), 3);
+            assert_eq!(profiles.get("laptop").unwrap().timings.data.len(), 3);
+            assert_eq!(profiles.get("desktop").unwrap().timings, get_mock_timings());
+        }
     }
 
     mod is_day_complete {
+        use super::get_timing;
         use crate::{
             day,
-            template::timings::{Timing, Timings},
+            template::timings::{PartTiming, Provenance, Timings},
         };
 
         #[test]
         fn handles_completed_days() {
             let timings = Timings {
-                data: vec![Timing {
-                    day: day!(1),
-                    part_1: Some("1ms".into()),
-                    part_2: Some("2ms".into()),
-                    total_nanos: 3_000_000_000_f64,
-                }],
+                data: vec![get_timing(1, Some(1e+9), Some(2e+9))],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), true);
+            assert_eq!(timings.is_day_complete(day!(1)), true);
         }
 
         #[test]
         fn handles_partial_days() {
             let timings = Timings {
-                data: vec![Timing {
-                    day: day!(1),
-                    part_1: Some("1ms".into()),
-                    part_2: None,
-                    total_nanos: 1_000_000_000_f64,
-                }],
+                data: vec![get_timing(1, Some(1e+9), None)],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), false);
+            assert_eq!(timings.is_day_complete(day!(1)), false);
+        }
+
+        #[test]
+        fn handles_unsolved_days() {
+            let mut timing = get_timing(1, Some(1e+9), None);
+            timing.part_2 = Some(PartTiming::unsolved());
+            let timings = Timings { data: vec![timing] };
+
+            assert_eq!(timings.is_day_complete(day!(1)), false);
+        }
+
+        #[test]
+        fn handles_debug_measurements() {
+            let mut timing = get_timing(1, Some(1e+9), Some(2e+9));
+            timing.set_provenance(Provenance {
+                measured_at: 0,
+                is_release: false,
+                threads: 1,
+            });
+            let timings = Timings { data: vec![timing] };
+
+            assert_eq!(timings.is_day_complete(day!(1)), false);
         }
 
         #[test]
         fn handles_uncompleted_days() {
             let timings = Timings {
-                data: vec![Timing {
-                    day: day!(1),
-                    part_1: None,
-                    part_2: None,
-                    total_nanos: 0.0,
-                }],
+                data: vec![get_timing(1, None, None)],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), false);
+            assert_eq!(timings.is_day_complete(day!(1)), false);
         }
     }
 
     mod merge {
+        use super::{get_mock_timings, get_timing};
         use crate::{
             day,
-            template::timings::{Timing, Timings},
+            template::timings::{PartTiming, Timings},
         };
 
-        use super::get_mock_timings;
-
         #[test]
         fn handles_disjunct_timings() {
             let timings = get_mock_timings();
             let other = Timings {
-                data: vec![Timing {
-                    day: day!(3),
-                    part_1: None,
-                    part_2: None,
-                    total_nanos: 0_f64,
-                }],
+                data: vec![get_timing(3, None, None)],
             };
             let merged = timings.merge(&other);
             assert_eq!(merged.data.len(), 4);
@@ -356,22 +1007,38 @@ mod tests {
             let timings = get_mock_timings();
 
             let other = Timings {
-                data: vec![Timing {
-                    day: day!(2),
-                    part_1: None,
-                    part_2: None,
-                    total_nanos: 0_f64,
-                }],
+                data: vec![get_timing(2, Some(1e+10), None)],
             };
             let merged = timings.merge(&other);
 
             assert_eq!(merged.data.len(), 3);
             assert_eq!(merged.data[0].day, day!(1));
             assert_eq!(merged.data[1].day, day!(2));
-            assert_eq!(merged.data[1].total_nanos, 0_f64);
+            assert_eq!(merged.data[1].part_nanos(1), Some(1e+10));
+            assert_eq!(merged.data[1].part_nanos(2), Some(4e+10));
             assert_eq!(merged.data[2].day, day!(4));
         }
 
+        #[test]
+        fn keeps_solved_parts() {
+            let timings = get_mock_timings();
+
+            let mut timing = get_timing(1, None, Some(5e+10));
+            timing.part_1 = Some(PartTiming::unsolved());
+            timing.parse_nanos = Some(100.0);
+            let merged = timings.merge(&Timings { data: vec![timing] });
+
+            assert_eq!(merged.data[0].part_nanos(1), Some(1e+10));
+            assert_eq!(merged.data[0].part_nanos(2), Some(5e+10));
+            assert_eq!(merged.data[0].parse_nanos, Some(100.0));
+
+            let mut timing = get_timing(4, None, None);
+            timing.part_2 = Some(PartTiming::unsolved());
+            let merged = timings.merge(&Timings { data: vec![timing] });
+
+            assert_eq!(merged.data[2].part_2, Some(PartTiming::unsolved()));
+        }
+
         #[test]
         fn handles_empty_timings() {
             let timings = Timings::default();
@@ -0,0 +1,130 @@
+/// Compares the stored timings of several machines side by side.
+use crate::template::table::render_table;
+use crate::template::timings::{format_nanos, Profile};
+
+/// Renders the runtime of every day per profile, relative to the first profile, followed by the hosts.
+pub fn render(profiles: &[&Profile]) -> String {
+    let Some(baseline) = profiles.first() else {
+        return "No timings stored yet.".into();
+    };
+
+    let mut days: Vec<_> = profiles
+        .iter()
+        .flat_map(|x| x.timings.data.iter().map(|t| t.day))
+        .collect();
+    days.sort_unstable();
+    days.dedup();
+
+    let header: Vec<String> = std::iter::once("Day".into())
+        .chain(profiles.iter().map(|x| x.name.clone()))
+        .collect();
+
+    let day_nanos = |profile: &Profile, day| {
+        profile
+            .timings
+            .data
+            .iter()
+            .find(|t| t.day == day)
+            .map(|t| t.total_nanos())
+            .filter(|x| *x > 0.0)
+    };
+
+    let cell =
+        |nanos: Option<f64>, baseline_nanos: Option<f64>, format: &dyn Fn(f64) -> String| match (
+            nanos,
+            baseline_nanos,
+        ) {
+            (None, _) => "-".into(),
+            (Some(x), Some(b)) if b > 0.0 && x != b => format!("{} (×{:.2})", format(x), x / b),
+            (Some(x), _) => format(x),
+        };
+
+    let mut rows: Vec<Vec<String>> = days
+        .into_iter()
+        .map(|day| {
+            std::iter::once(day.to_string())
+                .chain(
+                    profiles
+                        .iter()
+                        .map(|x| cell(day_nanos(x, day), day_nanos(baseline, day), &format_nanos)),
+                )
+                .collect()
+        })
+        .collect();
+
+    rows.push(
+        std::iter::once("Total".into())
+            .chain(profiles.iter().map(|x| {
+                cell(
+                    Some(x.timings.total_millis()),
+                    Some(baseline.timings.total_millis()),
+                    &|x| format!("{x:.2}ms"),
+                )
+            }))
+            .collect(),
+    );
+
+    let hosts: Vec<String> = profiles
+        .iter()
+        .map(|x| match &x.host {
+            Some(host) => format!(
+                "{}: {}, {} cores, {}, {}",
+                x.name, host.cpu, host.cores, host.os, host.rustc
+            ),
+            None => format!("{}: unknown host", x.name),
+        })
+        .collect();
+
+    format!("{}\n\n{}", render_table(&header, rows), hosts.join("\n"))
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::render;
+    use crate::template::host::Host;
+    use crate::template::timings::{PartTiming, Profile, Timing, Timings};
+
+    fn get_profile(name: &str, host: Option<Host>, nanos: &[(u8, f64)]) -> Profile {
+        Profile {
+            name: name.into(),
+            host,
+            timings: Timings {
+                data: nanos
+                    .iter()
+                    .map(|(day, nanos)| Timing {
+                        part_1: Some(PartTiming::solved(*nanos)),
+                        ..Timing::new(crate::template::Day::new(*day).unwrap())
+                    })
+                    .collect(),
+            },
+        }
+    }
+
+    #[test]
+    fn renders_comparison() {
+        let host = Host {
+            cpu: "Apple M1".into(),
+            cores: 8,
+            os: "macos aarch64".into(),
+            rustc: "rustc 1.83.0".into(),
+        };
+        let laptop = get_profile("laptop", Some(host), &[(1, 1e+6), (2, 2e+6)]);
+        let ci = get_profile("ci", None, &[(1, 2e+6)]);
+
+        let expected = [
+            "Day   │ laptop │ ci",
+            "──────┼────────┼───────────────",
+            "01    │ 1.0ms  │ 2.0ms (×2.00)",
+            "02    │ 2.0ms  │ -",
+            "Total │ 3.00ms │ 2.00ms (×0.67)",
+            "",
+            "laptop: Apple M1, 8 cores, macos aarch64, rustc 1.83.0",
+            "ci: unknown host",
+        ]
+        .join("\n");
+
+        assert_eq!(render(&[&laptop, &ci]), expected);
+    }
+}
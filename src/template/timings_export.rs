@@ -0,0 +1,271 @@
+/// Exports timings as CSV or JSON lines with one row per part and numeric durations.
+/// Rows carry the date and commit of their snapshot, so several snapshots can be exported side by side.
+/// Uncommitted timings are dated by their measurement and attributed to the commit they were measured on.
+use std::str::FromStr;
+
+use tinyjson::JsonValue;
+
+use crate::template::timings::PartTiming;
+use crate::template::timings_history::Snapshot;
+
+static COLUMNS: [&str; 10] = [
+    "day",
+    "part",
+    "nanos",
+    "median_nanos",
+    "spread_nanos",
+    "base_nanos",
+    "samples",
+    "peak_memory",
+    "date",
+    "commit",
+];
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Format {
+    Csv,
+    JsonLines,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "csv" => Ok(Format::Csv),
+            "jsonl" | "json-lines" => Ok(Format::JsonLines),
+            other => Err(format!(
+                "unknown export format \"{other}\", expected \"csv\" or \"jsonl\"."
+            )),
+        }
+    }
+}
+
+impl Format {
+    /// Guesses the format from the extension of a file path.
+    pub fn from_path(path: &str) -> Option<Self> {
+        let (_, extension) = path.rsplit_once('.')?;
+        Format::from_str(extension).ok()
+    }
+}
+
+/// A single measured part.
+#[derive(Clone, Debug, PartialEq)]
+struct Row {
+    day: u8,
+    part: u8,
+    timing: PartTiming,
+    date: Option<String>,
+    commit: Option<String>,
+}
+
+impl Row {
+    fn values(&self) -> [JsonValue; 10] {
+        let optional_number = |x: Option<f64>| x.map_or(JsonValue::Null, JsonValue::Number);
+        let optional_string = |x: &Option<String>| {
+            x.as_ref()
+                .map_or(JsonValue::Null, |x| JsonValue::String(x.clone()))
+        };
+        let timing = &self.timing;
+
+        #[allow(clippy::cast_precision_loss)]
+        [
+            JsonValue::Number(f64::from(self.day)),
+            JsonValue::Number(f64::from(self.part)),
+            JsonValue::Number(timing.nanos),
+            optional_number(timing.median_nanos),
+            optional_number(timing.spread_nanos),
+            optional_number(timing.base_nanos),
+            optional_number(timing.samples.map(|x| x as f64)),
+            optional_number(timing.peak_memory.map(|x| x as f64)),
+            optional_string(&self.date),
+            optional_string(&self.commit),
+        ]
+    }
+}
+
+fn collect_rows(snapshots: &[Snapshot], head: Option<&str>) -> Vec<Row> {
+    snapshots
+        .iter()
+        .flat_map(|snapshot| {
+            snapshot.timings.data.iter().flat_map(move |timing| {
+                [(1, &timing.part_1), (2, &timing.part_2)]
+                    .into_iter()
+                    .filter_map(move |(part, part_timing)| {
+                        let part_timing = part_timing.clone().filter(PartTiming::is_solved)?;
+                        let measured_at = part_timing.provenance.map(|x| x.measured_at);
+
+                        Some(Row {
+                            day: timing.day.into_inner(),
+                            part,
+                            date: snapshot
+                                .date
+                                .clone()
+                                .or_else(|| measured_at.map(format_date)),
+                            commit: snapshot.commit.clone().or_else(|| head.map(Into::into)),
+                            timing: part_timing,
+                        })
+                    })
+            })
+        })
+        .collect()
+}
+
+/// Renders one row per solved part. `head` is the commit that uncommitted timings are attributed to.
+pub fn render(snapshots: &[Snapshot], format: Format, head: Option<&str>) -> String {
+    let rows = collect_rows(snapshots, head);
+
+    let lines: Vec<String> = match format {
+        Format::Csv => std::iter::once(COLUMNS.join(","))
+            .chain(rows.iter().map(render_csv_row))
+            .collect(),
+        Format::JsonLines => rows.iter().map(render_json_row).collect(),
+    };
+
+    let mut output = lines.join("\n");
+    output.push('\n');
+    output
+}
+
+fn render_csv_row(row: &Row) -> String {
+    row.values()
+        .iter()
+        .map(|value| match value {
+            JsonValue::Null => String::new(),
+            JsonValue::String(s) if s.contains([',', '"', '\n']) => {
+                format!("\"{}\"", s.replace('"', "\"\""))
+            }
+            JsonValue::String(s) => s.clone(),
+            other => other.stringify().unwrap_or_default(),
+        })
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+/// Formats seconds since the unix epoch as `YYYY-MM-DD` in UTC.
+/// See: <http://howardhinnant.github.io/date_algorithms.html#civil_from_days>
+#[allow(clippy::cast_possible_wrap)]
+fn format_date(seconds: u64) -> String {
+    let days = (seconds / 86_400) as i64 + 719_468;
+    let era = days.div_euclid(146_097);
+    let doe = days - era * 146_097;
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+
+    format!("{year:04}-{month:02}-{day:02}")
+}
+
+/// Objects are written by hand so that keys keep the column order.
+fn render_json_row(row: &Row) -> String {
+    let fields: Vec<String> = COLUMNS
+        .iter()
+        .zip(row.values())
+        .map(|(key, value)| format!("\"{key}\":{}", value.stringify().unwrap_or_default()))
+        .collect();
+
+    format!("{{{}}}", fields.join(","))
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::str::FromStr;
+
+    use tinyjson::JsonValue;
+
+    use super::{format_date, render, Format};
+    use crate::day;
+    use crate::template::timings::{PartTiming, Provenance, Timing, Timings};
+    use crate::template::timings_history::Snapshot;
+
+    fn get_mock_snapshots() -> Vec<Snapshot> {
+        vec![
+            Snapshot {
+                commit: Some("abc123".into()),
+                date: Some("2024-12-01".into()),
+                timings: Timings {
+                    data: vec![Timing {
+                        part_1: Some(PartTiming::solved(74.13)),
+                        part_2: Some(PartTiming::unsolved()),
+                        ..Timing::new(day!(1))
+                    }],
+                },
+            },
+            Snapshot {
+                commit: None,
+                date: None,
+                timings: Timings {
+                    data: vec![Timing {
+                        part_1: Some(PartTiming {
+                            provenance: Some(Provenance {
+                                measured_at: 1_733_097_600,
+                                is_release: true,
+                                threads: 8,
+                            }),
+                            ..PartTiming::solved(70.0)
+                        }),
+                        part_2: Some(PartTiming {
+                            samples: Some(667),
+                            median_nanos: Some(1_400_000.0),
+                            spread_nanos: Some(2500.0),
+                            peak_memory: Some(2048),
+                            base_nanos: Some(3_000_000.0),
+                            ..PartTiming::solved(1_500_000.0)
+                        }),
+                        ..Timing::new(day!(1))
+                    }],
+                },
+            },
+        ]
+    }
+
+    #[test]
+    fn exports_csv() {
+        let expected = [
+            "day,part,nanos,median_nanos,spread_nanos,base_nanos,samples,peak_memory,date,commit",
+            "1,1,74.13,,,,,,2024-12-01,abc123",
+            "1,1,70,,,,,,2024-12-02,def456",
+            "1,2,1500000,1400000,2500,3000000,667,2048,,def456",
+            "",
+        ]
+        .join("\n");
+
+        assert_eq!(
+            render(&get_mock_snapshots(), Format::Csv, Some("def456")),
+            expected
+        );
+    }
+
+    #[test]
+    fn formats_dates() {
+        assert_eq!(format_date(0), "1970-01-01");
+        assert_eq!(format_date(1_733_097_599), "2024-12-01");
+        assert_eq!(format_date(1_709_164_800), "2024-02-29");
+    }
+
+    #[test]
+    fn exports_json_lines() {
+        let output = render(&get_mock_snapshots(), Format::JsonLines, None);
+        let lines: Vec<&str> = output.lines().collect();
+
+        assert_eq!(lines.len(), 3);
+        assert_eq!(
+            lines[0],
+            r#"{"day":1,"part":1,"nanos":74.13,"median_nanos":null,"spread_nanos":null,"base_nanos":null,"samples":null,"peak_memory":null,"date":"2024-12-01","commit":"abc123"}"#
+        );
+        assert!(lines.iter().all(|x| JsonValue::from_str(x).is_ok()));
+    }
+
+    #[test]
+    fn detects_formats() {
+        assert_eq!(Format::from_path("out/timings.csv"), Some(Format::Csv));
+        assert_eq!(Format::from_path("timings.jsonl"), Some(Format::JsonLines));
+        assert_eq!(Format::from_path("timings"), None);
+        assert!(Format::from_str("xml").is_err());
+    }
+}
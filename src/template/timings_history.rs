@@ -0,0 +1,112 @@
+/// Loads earlier versions of the stored timings from the git history of `data/timings.json`.
+/// Every commit that touched the file is one snapshot, so benchmarks have a history once they are committed.
+use std::fs;
+use std::process::{Command, Stdio};
+
+use crate::template::timings::{get_file_path, TimingProfiles, Timings};
+
+/// Stored timings at a point in time.
+#[derive(Clone, Debug)]
+pub struct Snapshot {
+    /// Hash of the commit, `None` for the working tree.
+    pub commit: Option<String>,
+    /// Commit date as `YYYY-MM-DD`, `None` for the working tree.
+    pub date: Option<String>,
+    pub timings: Timings,
+}
+
+/// Returns the committed snapshots of the timings of `profile`, oldest first.
+/// Uncommitted changes to the timings are appended as a snapshot of the working tree.
+/// Without a profile, the profile of this machine is loaded (see [`TimingProfiles::select`]).
+pub fn load(profile: Option<&str>) -> Vec<Snapshot> {
+    let log = git(&["log", "--format=%H %cs", "--", get_file_path()]).unwrap_or_default();
+
+    let mut committed: Vec<(&str, &str, String)> = parse_log(&log)
+        .into_iter()
+        .filter_map(|(commit, date)| {
+            let json = git(&["show", &format!("{commit}:{}", get_file_path())])?;
+            Some((commit, date, json))
+        })
+        .collect();
+
+    committed.reverse();
+
+    let latest = committed.last().map(|(_, _, json)| json.clone());
+
+    let mut snapshots: Vec<Snapshot> = committed
+        .into_iter()
+        .filter_map(|(commit, date, json)| parse_snapshot(Some(commit), Some(date), json, profile))
+        .collect();
+
+    if let Ok(json) = fs::read_to_string(get_file_path()) {
+        if latest.as_ref() != Some(&json) {
+            snapshots.extend(parse_snapshot(None, None, json, profile));
+        }
+    }
+
+    snapshots
+}
+
+fn parse_snapshot(
+    commit: Option<&str>,
+    date: Option<&str>,
+    json: String,
+    profile: Option<&str>,
+) -> Option<Snapshot> {
+    match TimingProfiles::try_from(json) {
+        Ok(profiles) => Some(Snapshot {
+            commit: commit.map(Into::into),
+            date: date.map(Into::into),
+            timings: profiles.select(profile)?.timings.clone(),
+        }),
+        Err(e) => {
+            eprintln!(
+                "Skipping timings of {}: {e}",
+                commit.unwrap_or("the working tree")
+            );
+            None
+        }
+    }
+}
+
+/// Hash of the checked out commit.
+pub fn head_commit() -> Option<String> {
+    git(&["rev-parse", "HEAD"]).map(|x| x.trim().to_string())
+}
+
+/// Parses lines of `<hash> <date>`.
+fn parse_log(log: &str) -> Vec<(&str, &str)> {
+    log.lines()
+        .filter_map(|line| line.trim().split_once(' '))
+        .collect()
+}
+
+fn git(args: &[&str]) -> Option<String> {
+    let output = Command::new("git")
+        .args(args)
+        .stdin(Stdio::null())
+        .stderr(Stdio::null())
+        .output()
+        .ok()?;
+
+    output
+        .status
+        .success()
+        .then(|| String::from_utf8_lossy(&output.stdout).to_string())
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::parse_log;
+
+    #[test]
+    fn parses_log() {
+        let log = "abc123 2024-12-02\ndef456 2024-12-01\n\n";
+        assert_eq!(
+            parse_log(log),
+            vec![("abc123", "2024-12-02"), ("def456", "2024-12-01")]
+        );
+    }
+}
@@ -0,0 +1,202 @@
+/// Renders grids and point sets to PNG images and animated GIFs in `data/visualizations`.
+/// Nothing is displayed or waited for, so visualizations also work in CI, over SSH and when run by `cargo all`.
+/// Solutions only render when started with `cargo solve <day> --visualize`, see [`is_enabled`].
+use std::fs::{self, File};
+use std::io::BufWriter;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use image::codecs::gif::{GifEncoder, Repeat};
+use image::{imageops, Delay, DynamicImage, Frame, ImageResult, Rgb, RgbImage};
+
+use crate::geometry::Point;
+use crate::grid::Grid;
+use crate::template::Day;
+
+pub type Color = [u8; 3];
+
+pub const BLACK: Color = [0, 0, 0];
+pub const WHITE: Color = [255, 255, 255];
+
+/// Whether the solution was started with the `--visualize` flag.
+pub fn is_enabled() -> bool {
+    std::env::args().any(|x| x == "--visualize")
+}
+
+/// Path of a visualization of `day`, e.g. `data/visualizations/14-tree.png`. Creates the directory if needed.
+pub fn output_path(day: Day, name: &str) -> PathBuf {
+    let dir = PathBuf::from("data").join("visualizations");
+    let _ = fs::create_dir_all(&dir);
+    dir.join(format!("{day}-{name}"))
+}
+
+/// Colors of cells, with a fallback color for all cells that are not listed.
+///
+/// ```
+/// # use advent_of_code::visualize::{Palette, BLACK, WHITE};
+/// let palette = Palette::new(BLACK).with('#', WHITE).with('O', [200, 120, 0]);
+/// assert_eq!(palette.color(&'#'), WHITE);
+/// assert_eq!(palette.color(&'.'), BLACK);
+/// ```
+#[derive(Clone, Debug)]
+pub struct Palette<T> {
+    colors: Vec<(T, Color)>,
+    fallback: Color,
+}
+
+impl<T: PartialEq> Palette<T> {
+    pub fn new(fallback: Color) -> Self {
+        Palette {
+            colors: vec![],
+            fallback,
+        }
+    }
+
+    #[must_use]
+    pub fn with(mut self, cell: T, color: Color) -> Self {
+        self.colors.push((cell, color));
+        self
+    }
+
+    pub fn color(&self, cell: &T) -> Color {
+        self.colors
+            .iter()
+            .find(|(x, _)| x == cell)
+            .map_or(self.fallback, |(_, color)| *color)
+    }
+}
+
+/// Draws every cell as a square of `scale` × `scale` pixels, colored by `color`.
+#[allow(clippy::cast_possible_truncation)]
+pub fn render_grid<T>(grid: &Grid<T>, color: impl Fn(&T) -> Color, scale: u32) -> RgbImage {
+    let scale = scale.max(1);
+    let image = RgbImage::from_fn(grid.width() as u32, grid.height() as u32, |x, y| {
+        Rgb(color(&grid[(x as usize, y as usize)]))
+    });
+
+    if scale == 1 {
+        image
+    } else {
+        imageops::resize(
+            &image,
+            image.width() * scale,
+            image.height() * scale,
+            imageops::FilterType::Nearest,
+        )
+    }
+}
+
+/// A grid of `width` × `height` cells that are `true` where one of `points` is. Points outside are ignored.
+pub fn point_grid(
+    points: impl IntoIterator<Item = Point>,
+    width: usize,
+    height: usize,
+) -> Grid<bool> {
+    let mut grid = Grid::new(width, height, false);
+
+    for point in points {
+        if let Some(cell) = grid.get_point_mut(point) {
+            *cell = true;
+        }
+    }
+
+    grid
+}
+
+/// Renders `grid` and saves it as a PNG image to `path`.
+pub fn save_png<T>(
+    grid: &Grid<T>,
+    color: impl Fn(&T) -> Color,
+    scale: u32,
+    path: &Path,
+) -> ImageResult<()> {
+    render_grid(grid, color, scale).save(path)
+}
+
+/// Writes frames to an animated GIF that loops forever. Frames are encoded as they are pushed.
+///
+/// ```no_run
+/// # use std::time::Duration;
+/// # use advent_of_code::{day, grid::Grid, visualize::{self, GifRecorder, Palette, BLACK, WHITE}};
+/// let palette = Palette::new(BLACK).with('#', WHITE);
+/// let mut grid = Grid::new(10, 10, '.');
+/// let mut gif = GifRecorder::create(&visualize::output_path(day!(14), "robots.gif"), Duration::from_millis(100))?;
+/// for i in 0..10 {
+///     grid[(i, i)] = '#';
+///     gif.push(&visualize::render_grid(&grid, |x| palette.color(x), 4))?;
+/// }
+/// # Ok::<(), image::ImageError>(())
+/// ```
+pub struct GifRecorder {
+    encoder: GifEncoder<BufWriter<File>>,
+    delay: Delay,
+}
+
+impl GifRecorder {
+    /// Creates the file at `path`. Every frame is shown for `delay`.
+    pub fn create(path: &Path, delay: Duration) -> ImageResult<Self> {
+        let mut encoder = GifEncoder::new(BufWriter::new(File::create(path)?));
+        encoder.set_repeat(Repeat::Infinite)?;
+
+        Ok(GifRecorder {
+            encoder,
+            delay: Delay::from_saturating_duration(delay),
+        })
+    }
+
+    pub fn push(&mut self, image: &RgbImage) -> ImageResult<()> {
+        let rgba = DynamicImage::ImageRgb8(image.clone()).into_rgba8();
+        self.encoder
+            .encode_frame(Frame::from_parts(rgba, 0, 0, self.delay))
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::Duration;
+
+    use super::{point_grid, render_grid, GifRecorder, Palette, BLACK, WHITE};
+    use crate::geometry::Point;
+    use crate::grid::Grid;
+
+    #[test]
+    fn renders_scaled_grids() {
+        let grid = Grid::parse("#.\n.O\n", |c| c);
+        let palette = Palette::new(BLACK).with('#', WHITE).with('O', [200, 0, 0]);
+        let image = render_grid(&grid, |x| palette.color(x), 3);
+
+        assert_eq!(image.dimensions(), (6, 6));
+        assert_eq!(image.get_pixel(2, 2).0, WHITE);
+        assert_eq!(image.get_pixel(3, 2).0, BLACK);
+        assert_eq!(image.get_pixel(5, 5).0, [200, 0, 0]);
+    }
+
+    #[test]
+    fn renders_points() {
+        let grid = point_grid(
+            [Point::new(1, 0), Point::new(-1, 0), Point::new(2, 2)],
+            3,
+            2,
+        );
+        assert_eq!(grid.find_all(|x| *x).collect::<Vec<_>>(), vec![(1, 0)]);
+    }
+
+    #[test]
+    fn records_gifs() {
+        let path = std::env::temp_dir().join("advent_of_code_visualize_test.gif");
+        let mut gif = GifRecorder::create(&path, Duration::from_millis(50)).unwrap();
+        let grid = point_grid([Point::new(0, 0)], 2, 2);
+
+        for _ in 0..3 {
+            gif.push(&render_grid(&grid, |x| if *x { WHITE } else { BLACK }, 2))
+                .unwrap();
+        }
+        drop(gif);
+
+        let bytes = std::fs::read(&path).unwrap();
+        assert!(bytes.starts_with(b"GIF89a"));
+        let _ = std::fs::remove_file(path);
+    }
+}